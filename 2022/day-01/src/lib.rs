@@ -0,0 +1,56 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        // Each elf's snacks are a block of lines, with a blank line between elves
+        blocks(input).into_iter().map(ints).collect()
+    }
+
+    fn part1(&self, nums: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(get_max_calories(nums))
+    }
+
+    fn part2(&self, nums: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_top_three_cal_totals(nums))
+    }
+}
+
+pub fn get_max_calories(list: &[Vec<i32>]) -> i32 {
+    list.iter()
+        .map(|entry| entry.iter().sum())
+        .max()
+        .unwrap()
+}
+
+pub fn sum_top_three_cal_totals(list: &[Vec<i32>]) -> i32 {
+    let mut totals: Vec<i32> = list.iter()
+        .map(|entry| entry.iter().sum())
+        .collect();
+    
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    totals.iter().take(3).sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let nums = Day01.parse(&read_from_file_as_string("example1_1.txt")?)?;
+
+        assert_eq!(get_max_calories(&nums), 24000);
+        assert_eq!(sum_top_three_cal_totals(&nums), 45000);
+        Ok(())
+    }
+}
@@ -0,0 +1,204 @@
+use aoc_helpers::{ * };
+use std::collections::HashMap;
+use std::fmt::Display;
+use lazy_static::lazy_static;
+
+#[derive(Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, parse_game)
+    }
+
+    // Part 1 reads the second column as the shape the player throws
+    fn part1(&self, games: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(evaluate_totals(games, Game::new))
+    }
+
+    // Part 2 reads it as the result the player needs
+    fn part2(&self, games: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(evaluate_totals(games, Game::from_strategy))
+    }
+}
+
+// Maps every possible combination of shapes played by each player to a win, lose, or draw for the player
+// (the first entry in the tuple). Wins are mapped to 6 points, draws to 3 points, losses to 0 points.
+lazy_static! {
+    static ref RULES: HashMap<(char, char), u32> = {
+        let mut m = HashMap::new();
+
+        // Handle all possible draws
+        for x in ['R', 'P', 'S'] {
+            m.insert((x, x), 3);
+        }
+
+        // Handle cases where the player played rock
+        m.insert(('R', 'S'), 6);
+        m.insert(('R', 'P'), 0);
+
+        // Handle cases where the player played paper
+        m.insert(('P', 'R'), 6);
+        m.insert(('P', 'S'), 0);
+
+        // Handle cases where the player played scissors
+        m.insert(('S', 'P'), 6);
+        m.insert(('S', 'R'), 0);
+
+        m
+    };
+}
+
+// Maps a tuple containing the elf's shape (rock/paper/scissors) and the desired result
+// for the player (win/lose/draw) to the shape the player needs to play (R/P/S)
+lazy_static! {
+    static ref SELECTOR: HashMap<(char, char), char> = {
+        let mut s = HashMap::new();
+
+        for x in ['R', 'P', 'S'] {
+            s.insert((x, 'D'), x);
+        }
+
+        s.insert(('R', 'W'), 'P');
+        s.insert(('R', 'L'), 'S');
+        
+        s.insert(('P', 'W'), 'S');
+        s.insert(('P', 'L'), 'R');
+
+        s.insert(('S', 'W'), 'R');
+        s.insert(('S', 'L'), 'P');
+
+        s
+    };
+}
+
+pub struct Game {
+    player_score: u32,
+}
+
+impl Game {
+
+    // Handles a round of the game, getting win/lose/draw for a combination of hands and calculating the
+    // player's score for this game
+    fn new(game: &[char]) -> Self {
+
+        // Create a Hand object for the player and elf
+        let player = Hand::new(game[1]);
+        let elf = Hand::new(game[0]);
+
+        // Calculate the score from evaluating the game for win/lose/draw
+        let eval_score = *RULES.get(&(player.shape, elf.shape)).unwrap();
+
+        // Calculate the player's total score from their win/lose/draw score plus the number of 
+        // points awarded for the player's selection
+        let player_score = eval_score + player.points;
+
+        Game { player_score }
+    }
+
+    // Handles a round of the game, calculating the shape the player needs to play from the desired result,
+    // and calculating the player's score for this round
+    fn from_strategy(game: &[char]) -> Self {
+        let elf = Hand::new(game[0]);
+
+        let desired_result: char = match game[1] {
+            'Z' => 'W',
+            'Y' => 'D',
+            'X' => 'L',
+            _ => '_',
+        };
+
+        let desired_shape = *SELECTOR.get(&(elf.shape, desired_result)).unwrap_or(&'F');
+        let player = Hand::new(desired_shape);
+
+        let eval_score = match desired_result {
+            'W' => 6,
+            'D' => 3,
+            'L' => 0,
+            _ => 0
+        };
+
+        let player_score = eval_score + player.points;
+        
+        Game { player_score }
+    }
+}
+
+// Handles an individual player's hand
+struct Hand {
+    shape: char,
+    points: u32,
+}
+
+impl Hand {
+    fn new(encoded_shape: char) -> Self {
+
+        // Maps the encoded shape chars (ABC, XYZ) to decoded shape chars (RPS). Part 2 passes
+        // the decoded chars straight through.
+        let shape: char = match encoded_shape {
+            'A' | 'X' | 'R' => 'R',
+            'B' | 'Y' | 'P' => 'P',
+            'C' | 'Z' | 'S' => 'S',
+            _ => '_'
+        };
+
+        // Get the number of points this player earned for their shape selection
+        // (rock = 1, paper = 2, scissors = 3)
+        let points: u32 = match shape {
+            'R' => 1,
+            'P' => 2,
+            'S' => 3,
+            _ => 0,
+        };
+
+        Hand { shape, points }
+    }
+}
+
+// Convert a vector of strings (lines from the input) to the vector of vectors of chars needed for processing
+// Each line is the elf's shape (A/B/C) and the second column (X/Y/Z), which the two parts read differently
+fn parse_game(line: &str) -> Result<Vec<char>, AocError> {
+    let (elf, player): (char, char) = Pattern::new("{} {}").parse(line)?;
+
+    if !('A'..='C').contains(&elf) {
+        return Err(AocError::parse(1, 1, format!("expected A, B or C, got {:?}", elf)));
+    }
+    if !('X'..='Z').contains(&player) {
+        return Err(AocError::parse(1, 3, format!("expected X, Y or Z, got {:?}", player)));
+    }
+
+    Ok(vec![elf, player])
+}
+
+// Calculate the player's total score across all games
+pub fn evaluate_totals(games: &[Vec<char>], play: fn(&[char]) -> Game) -> u32 {
+    let mut running_total = 0;
+
+    // Iterate across all games, creating a Game object for each to calculate the player's current total
+    for line in games {
+        let game = play(line);
+        running_total += game.player_score;
+    }
+
+    running_total
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let games = Day02.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(evaluate_totals(&games, Game::new), 15);
+        assert_eq!(evaluate_totals(&games, Game::from_strategy), 12);
+        Ok(())
+    }
+}
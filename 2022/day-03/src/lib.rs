@@ -0,0 +1,101 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(calculate_total(lines))
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(calculate_group_total(&get_groups_from_lines(lines)))
+    }
+}
+
+pub fn calculate_total(lines: &[String]) -> u32 {
+    let mut running_total = 0;
+
+    // Iterate over all lines in the input vector, separate each line into a separate rucksack,
+    // find the duplicate character, calculate its priority, and add it to the running total
+    for line in lines {
+        let rucksack = get_rucksack_from_lines(line);
+        let dup = find_duplicate_item(rucksack.0, rucksack.1);
+        running_total += char_to_priority(dup.expect("Duplicate item returned a None value"));
+    }
+
+    running_total
+}
+
+// Split a line into a rucksack with two equally sized compartments (substrings)
+fn get_rucksack_from_lines(line: &str) -> (&str, &str) {
+    let mid = line.len() / 2;
+    (&line[..mid], &line[mid..])
+}
+
+// Return the duplicate character across two strings, or None if one isn't found
+fn find_duplicate_item(entry1: &str, entry2: &str) -> Option<char> {
+    entry1.chars().find(|&ch| entry2.contains(ch))
+}
+
+// Iterates through all groups of 3 in the input, finds the duplicate value in each, and 
+// adds its priority to a running total. Then returns the total.
+pub fn calculate_group_total(groups: &[Vec<String>]) -> u32 {
+    let mut running_total = 0;
+
+    for group in groups {
+        let dup = find_group_duplicate(group);
+        running_total += char_to_priority(dup.expect("No duplicate items :("));
+    }
+
+    running_total
+}
+
+// Returns the duplicate character that exists in all three strings in the group, or None if none exists
+fn find_group_duplicate(group: &[String]) -> Option<char> {
+    group[0].chars().find(|&ch| group[1].contains(ch) && group[2].contains(ch))
+}
+
+// Split the puzzle input (as lines) into groups of 3
+pub fn get_groups_from_lines(lines: &[String]) -> Vec<Vec<String>> {
+    lines
+    .chunks(3)
+    .map(|chunk| chunk.to_vec())
+    .collect()
+}
+
+// Calculate a given character's priority
+fn char_to_priority(ch: char) -> u32 {
+
+    // If the character is lowercase, we need to subtract 96 from its ASCII value to get the required
+    // value. If it's uppercase, subtract 38.
+    match ch {
+        'a'..='z' => (ch as u32) - ('a' as u32) + 1,
+        'A'..='Z' => (ch as u32) - ('A' as u32) + 27,
+        _ => 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let lines = Day03.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(calculate_total(&lines), 157);
+        assert_eq!(calculate_group_total(&get_groups_from_lines(&lines)), 70);
+        Ok(())
+    }
+}
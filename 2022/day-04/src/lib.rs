@@ -0,0 +1,66 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input = Vec<(Interval<u32>, Interval<u32>)>;
+
+    // Parse the input into a vector of pairs of section ranges
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, |s| {
+            let (left, right) = s
+                .split_once(',')
+                .ok_or_else(|| AocError::parse(1, 1, "expected two comma-separated ranges"))?;
+            Ok((left.parse()?, right.parse()?))
+        })
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_contained_pairs(pairs))
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_overlapping_pairs(pairs))
+    }
+}
+
+// Counts the number of pairs where one range fully contains the other
+pub fn count_contained_pairs(pairs: &[(Interval<u32>, Interval<u32>)]) -> usize {
+    pairs
+        .iter()
+        .filter(|(a, b)| a.contains_interval(b) || b.contains_interval(a))
+        .count()
+}
+
+// This is slightly different than part 1. In this case, we only need the two ranges to
+// overlap at all.
+pub fn count_overlapping_pairs(pairs: &[(Interval<u32>, Interval<u32>)]) -> usize {
+    pairs
+        .iter()
+        .filter(|(a, b)| a.overlaps(b))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let pairs = Day04.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(count_contained_pairs(&pairs), 2);
+        assert_eq!(count_overlapping_pairs(&pairs), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn bad_range_reports_its_line() {
+        assert!(matches!(Day04.parse("2-4,6-8\n2-3,4-x\n"), Err(AocError::Parse { line: 2, .. })));
+    }
+}
@@ -0,0 +1,147 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day05;
+
+// The initial stacks of crates and the list of moves as tuples: number of crates, src, dst
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    instructions: Vec<(usize, usize, usize)>,
+}
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input = Procedure;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let input: Vec<String> = input.lines().map(String::from).collect();
+
+        // The puzzle input contains a chart of the initial crate positions and a list of
+        // moves, split by an empty row.
+        let split_index = input
+            .iter()
+            .position(|s| s.is_empty())
+            .ok_or_else(|| AocError::parse(1, 1, "missing blank line between crates and moves"))?;
+        let (crates, moves) = input.split_at(split_index);
+
+        Ok(Procedure {
+            stacks: generate_stacks(crates),
+            instructions: parse_moves(moves, split_index + 2)?,
+        })
+    }
+
+    fn part1(&self, procedure: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(rearrange(procedure, move_crates_one_at_a_time))
+    }
+
+    fn part2(&self, procedure: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(rearrange(procedure, move_crates_in_order))
+    }
+}
+
+// One of the two crane models: takes the stacks, the number of crates to move, and the (0-indexed)
+// source and destination stacks, and hands back the stacks after the move
+pub type Mover = fn(Vec<Vec<char>>, usize, usize, usize) -> Vec<Vec<char>>;
+
+// Go through the list of instructions and make the moves as requested, then read off the
+// crate at the top of each stack. We have to subtract 1 from the src and dst indices,
+// because the puzzle input is 1-indexed.
+pub fn rearrange(procedure: &Procedure, mover: Mover) -> String {
+    let mut stacks = procedure.stacks.clone();
+
+    for instr in &procedure.instructions {
+        stacks = mover(stacks, instr.0, instr.1 - 1, instr.2 - 1);
+    }
+
+    stacks
+        .iter()
+        .filter_map(|stack| stack.last().copied())
+        .collect()
+}
+
+// Take the vector of stacks, the number of crates to move, and the source and destination
+// stacks. Make the requested moves and return the updated vector of stacks.
+fn move_crates_one_at_a_time(mut stacks: Vec<Vec<char>>, n: usize, src: usize, dst: usize) -> Vec<Vec<char>> {
+    for _ in 0..n {
+        let ch = stacks[src].pop().expect("Failed to pop char");
+        stacks[dst].push(ch);
+    }
+
+    stacks
+}
+
+// Take the vector of stacks, the number of crates to move, and the source and destination
+// stacks. Moves the requested number of crates from src to dst, maintaining their order.
+// The requirement to maintain the order of the moved crates ended up making it a little more 
+// complex than part 1, but not really.
+fn move_crates_in_order(mut stacks: Vec<Vec<char>>, n: usize, src: usize, dst: usize) -> Vec<Vec<char>> {
+
+    // Since we need to "pop" multiple crates in a single go, we can use the split_off() method.
+    // Just need to get the index to split at.
+    let split_index = stacks[src].len() - n;
+    let ch: Vec<_> = stacks[src].split_off(split_index);
+
+    // Add the crates to the destination stack
+    stacks[dst].extend(ch);
+
+    stacks
+}
+
+// Parse the list of moves into a vector of tuples with usize values
+// corresponding to the number of crates to move, source, and destination.
+// The moves start after the blank line, which is first_line - 1.
+fn parse_moves(moves: &[String], first_line: usize) -> Result<Vec<(usize, usize, usize)>, AocError> {
+    let step = Pattern::new("move {} from {} to {}");
+
+    moves
+        .iter()
+        .skip(1)
+        .enumerate()
+        .map(|(i, s)| step.parse(s).map_err(|e| on_line(e, first_line + i)))
+        .collect()
+}
+
+
+// Generate a vector of stacks of characters to represent the stacks of crates
+fn generate_stacks(crates: &[String]) -> Vec<Vec<char>> {
+    
+    // Calculate the number of stacks from the length of a horizontal line
+    let num_stacks = ((crates[0].len() - 1) / 4) + 1;
+
+    // Initialize the vector of stacks
+    let mut stacks = vec![Vec::<char>::new(); num_stacks];
+
+    // Iterate over the lines in crates, from bottom to top, adding the chars
+    // in the correct indices to their respective stacks
+    for line in crates.iter().rev().skip(1) {
+
+        // i => index in the string of the current char
+        // j => index of the stack that char belongs to
+        for i in (1..= (num_stacks * 4)).step_by(4) {
+            let j = (i - 1) / 4;
+            let ch = line.chars().nth(i).expect("Index in the string seems to be out of range");
+            if !ch.is_whitespace() {
+                stacks[j].push(ch);
+            }
+        }
+
+    }
+    stacks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let procedure = Day05.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(rearrange(&procedure, move_crates_one_at_a_time), "CMZ");
+        assert_eq!(rearrange(&procedure, move_crates_in_order), "MCD");
+        Ok(())
+    }
+}
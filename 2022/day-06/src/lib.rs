@@ -0,0 +1,82 @@
+use aoc_helpers::{ * };
+use std::collections::{ HashSet, VecDeque };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<impl Display, AocError> {
+        find_marker(input, 4).ok_or_else(|| AocError::parse(1, 1, "no start-of-packet marker found"))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<impl Display, AocError> {
+        find_marker(input, 14).ok_or_else(|| AocError::parse(1, 1, "no start-of-message marker found"))
+    }
+}
+
+// Returns the index just past the first run of `size` distinct chars. Part 1 looks for a
+// run of 4, part 2 a run of 14 - that was the only change needed between the two.
+pub fn find_marker(input: &str, size: usize) -> Option<usize> {
+
+    // Initialize a VecDeque to act as a buffer for parsing `size` chars at a time
+    let mut buffer: VecDeque<char> = VecDeque::new();
+    for (i, ch) in input.chars().enumerate() {
+
+        // Push chars to fill the buffer initially
+        if buffer.len() < size {
+            buffer.push_back(ch);
+
+        } else {
+
+            // Check if all elements in the buffer are unique (i.e., collect them into a
+            // HashSet and check the length of the set against the length of the buffer).
+            // If so, we've reached the start-of-packet marker, so return the current index.
+            if buffer.iter().cloned().collect::<HashSet<_>>().len() == buffer.len() {
+                return Some(i);
+            }
+
+            // Otherwise, drop the first char in the buffer and push the next char in the string      
+            buffer.pop_front();
+            buffer.push_back(ch);          
+
+        }
+    }
+
+    // Return None if no start-of-packet marker is found in the string
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn std::error::Error>> {
+        let test_cases = vec![
+            ("example1.txt", 7, 19),
+            ("example2.txt", 5, 23),
+            ("example3.txt", 6, 23),
+            ("example4.txt", 10, 29),
+            ("example5.txt", 11, 26),
+        ];
+
+        for (filename, packet, message) in test_cases {
+            let input = Day06.parse(&read_from_file_as_string(filename)?)?;
+
+            assert_eq!(find_marker(&input, 4), Some(packet), "Part 1 failed for file {}", filename);
+            assert_eq!(find_marker(&input, 14), Some(message), "Part 2 failed for file {}", filename);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input = Grid<u8>;
+
+    // Each tree is a single digit height
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse_with(input, |ch| ch.to_digit(10).map(|d| d as u8).ok_or(()))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_visible(grid))
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(max_scenic_score(grid))
+    }
+}
+
+// Iterate across all entries in the grid, checking whether each entry is visible. If so,
+// increment the running total.
+pub fn count_visible(grid: &Grid<u8>) -> u32 {
+    let rows = grid.height();
+    let cols = grid.width();
+
+    let mut running_total = 0;
+
+    for y in 0..rows {
+        for x in 0..cols {
+            if is_visible(grid, x, y) {
+                running_total += 1;
+            }
+        }
+    }
+
+    running_total
+}
+
+// Calculate the scenic score for every entry in the grid and return the best one
+pub fn max_scenic_score(grid: &Grid<u8>) -> u32 {
+    grid.positions()
+        .map(|(x, y)| scenic_score(grid, x as usize, y as usize))
+        .max()
+        .unwrap()
+}
+
+// Checks whether a position in the grid (given by (x, y)) is visible from the
+// outside. If so, return true, otherwise return false.
+fn is_visible(grid: &Grid<u8>, x: usize, y: usize) -> bool {
+
+    // Calculate the max possible x and y values for the grid to prevent overflowing
+    let max_x = grid.width() - 1;
+    let max_y = grid.height() - 1;
+
+    // If the current position is on the exterior of the grid, it's visible by definition,
+    // so just return true.
+    if x == 0 || x == max_x || y == 0 || y == max_y {
+        return true;
+    }
+
+    // Check each direction for the given position. If visible_from() returns true for any
+    // direction, this function immediately returns true without further processing.
+    for dir in Dir4::ALL {
+        if visible_from(grid, x, y, dir) {
+            return true;
+        }
+    }
+
+    // If we've evaluated all directions without returning true, this position is not visible.
+    false
+}
+
+// This checks a position for visibility from a specific direction. Returns true if
+// the position is visible from that direction, otherwise false.
+fn visible_from(grid: &Grid<u8>, x: usize, y: usize, dir: Dir4) -> bool {
+
+    // Record the height of the tree at the current position
+    let current_height = grid[(x, y)];
+
+    // In general, we don't need to check whether we're on the exterior of the grid because
+    // that check is done in is_visible() before this function is called. So ignore that case.
+
+    // Walk from the current position to the edge of the grid in that direction, immediately
+    // returning false if we come across a tree of equal or greater height than the tree we're
+    // evaluating. If we get to the edge without returning, the tree must be visible.
+    let mut pos = (x as i32, y as i32);
+    while let Some(next @ (nx, ny)) = dir.step_on(grid, pos) {
+        if grid[(nx as usize, ny as usize)] >= current_height {
+            return false;
+        }
+        pos = next;
+    }
+
+    true
+}
+
+// Calculates the scenic score for a given position in the grid by checking visibility
+// in each direction and multiplying the four scores together
+fn scenic_score(grid: &Grid<u8>, x: usize, y: usize) -> u32 {
+
+    let max_x = grid.width() - 1;
+    let max_y = grid.height() - 1;
+
+    // If the tree is on the edge of the map, the scenic score will be 0, so we don't
+    // need to do any more processing.
+    if x == 0 || y == 0 || x == max_x || y == max_y {
+        return 0;
+    }
+
+    let mut scenic_score: u32 = 1;
+
+    // Check each direction for the given position, multiplying scenic_score by the
+    // result returned for each direction
+    for dir in Dir4::ALL {
+        let current_score = viewing_distance(grid, x, y, dir);
+        scenic_score *= current_score;
+    }
+
+    scenic_score
+}
+
+// This checks the number of trees visible from a given position in a given
+// direction. We again don't need to check the case where the position is on
+// the edge, because the calling function (scenic_score()) handles that case.
+fn viewing_distance(grid: &Grid<u8>, x: usize, y: usize, dir: Dir4) -> u32 {
+
+    // Record the height of the tree at the current position
+    let current_height = grid[(x, y)];
+
+    let mut distance = 0;
+
+    // Walk from the current position until we reach a tree at least as tall (which we
+    // can still see) or the edge of the grid, counting the trees along the way
+    let mut pos = (x as i32, y as i32);
+    while let Some(next @ (nx, ny)) = dir.step_on(grid, pos) {
+        distance += 1;
+
+        if grid[(nx as usize, ny as usize)] >= current_height {
+            break;
+        }
+
+        pos = next;
+    }
+
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = Day08.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(count_visible(&grid), 21);
+        assert_eq!(max_scenic_score(&grid), 8);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_01::Day01;
+
+fn main() -> ExitCode {
+    run_solution::<Day01>(1)
+}
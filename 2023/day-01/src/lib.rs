@@ -0,0 +1,149 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<impl Display, AocError> {
+        sum_digits(lines)
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_spelled_digits(lines))
+    }
+}
+
+pub fn sum_digits(lines: &[String]) -> Result<u32, AocError> {
+    let mut total: u32 = 0;
+
+    for (i, line) in lines.iter().enumerate() {
+        let mut first: Option<char> = None;
+        let mut last: Option<char> = None;
+
+        // iterate through the characters in the current line and look for numbers
+        for c in line.chars() {
+            if c.is_numeric() {
+
+                // if 'first' is uninitialized, this is the first numeric character in the line, so set 'first'
+                if first.is_none() { first = Some(c); }
+
+                // every time you come across a number, set 'last' to that number
+                // this handles the case where there's only one digit in the string, so first == last
+                last = Some(c);
+
+            }
+        }
+
+        let (Some(first), Some(last)) = (first, last) else {
+            return Err(AocError::parse(i + 1, 1, format!("no digits in {:?}", line)));
+        };
+
+        // concatenate the first and last characters and parse them into an integer
+        let s = format!("{}{}", first, last);
+        let num = s.parse::<u32>().map_err(|e| AocError::parse(i + 1, 1, e.to_string()))?;
+
+        // add the current 2-digit number to the running total
+        total += num;
+    }
+
+    Ok(total)
+}
+
+pub fn sum_spelled_digits(lines: &[String]) -> u32 {
+    let mut first = 0;
+    let mut last = 0;
+    let mut total: u32 = 0;
+
+    // I know zeros aren't included in this puzzle, but leaving them in makes indexing a lot simpler
+    let numbers = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "0",
+        "1",
+        "2",
+        "3",
+        "4",
+        "5",
+        "6",
+        "7",
+        "8",
+        "9"
+    ];
+
+    for l in lines {
+        let mut positions: Vec<Vec<usize>> = Vec::new();
+
+        let mut first_i = usize::MAX;
+        let mut last_i = 0;
+
+        // Find any numbers (text or numeric) in the string and record the indices where they appear.
+        // For each entry in the numbers list, find any instances of that entry in the given line and
+        // store the index in the string where it appears. There can be multiple, so positions is a
+        // vector of vectors of usizes.
+        for num in numbers {
+            positions.push(l.match_indices(num).map(|(i, _)|i).collect());
+        }
+
+        // Find the first and last entry from the positions vector
+        for (i, pos) in positions.iter().enumerate() {
+
+        // first_i is initialized to usize::MAX and last_i is initialized to 0 so we can find the min
+        // and max values in the position vector to get the first and last numbers in the string.
+        // There's probably a much better way to do this, but here we are.
+
+        // This gives us the first and last numbers in the string, but as their index in the numbers
+        // array. To get the actual number, we take that index mod 10. If we had taken zero out of the
+        // array, this would have been a lot messier.
+            for &x in pos {
+                if x < first_i {
+                    first_i = x;
+                    first = i % 10;
+                }
+
+                if x >= last_i {
+                    last_i = x;
+                    last = i % 10;
+                }
+            }
+        }
+
+        // The first and last digits make up a two digit number
+        total += (first * 10 + last) as u32;
+    }
+
+    total
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let first = Day01.parse(&read_from_file_as_string("example1.txt")?)?;
+        let second = Day01.parse(&read_from_file_as_string("example2.txt")?)?;
+
+        assert_eq!(sum_digits(&first)?, 142);
+        assert_eq!(sum_spelled_digits(&second), 281);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_02::Day02;
+
+fn main() -> ExitCode {
+    run_solution::<Day02>(1)
+}
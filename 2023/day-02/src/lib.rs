@@ -0,0 +1,129 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day02;
+
+// Each game is a list of draws, and each draw is a list of (number of cubes, color) pairs. The game's ID is
+// just its position in the list, starting from 1.
+pub type Game = Vec<Vec<(u32, String)>>;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, parse_game)
+    }
+
+    fn part1(&self, games: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_possible_games(games))
+    }
+
+    fn part2(&self, games: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_powers(games))
+    }
+}
+
+fn parse_game(line: &str) -> Result<Game, AocError> {
+    let (_, game) = line
+        .split_once(": ")
+        .ok_or_else(|| AocError::parse(1, 1, format!("expected a game, got {:?}", line)))?;
+
+    game.split("; ")
+        .map(|draw| {
+            draw.split(", ")
+                .map(|color| {
+                    let (number, color) = color
+                        .split_once(' ')
+                        .ok_or_else(|| AocError::parse(1, 1, format!("expected a number and a color, got {:?}", color)))?;
+                    let number = number
+                        .parse::<u32>()
+                        .map_err(|_| AocError::parse(1, 1, format!("bad number of cubes: {:?}", number)))?;
+
+                    Ok((number, color.to_string()))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// A game is possible if no draw pulled out more than 12 red, 13 green or 14 blue cubes. Add up the IDs of the
+// ones that are.
+pub fn sum_possible_games(games: &[Game]) -> usize {
+    let mut total = 0;
+
+    'game: for (index, game) in games.iter().enumerate() {
+        for draw in game {
+            for (number, color) in draw {
+                if color == "red" && *number > 12
+                    || color == "green" && *number > 13
+                    || color == "blue" && *number > 14
+                {
+                    continue 'game;
+                }
+            }
+        }
+        total += index + 1;
+    }
+
+    total
+}
+
+// The fewest cubes of each color a game could have been played with is the most of that color seen in any one
+// draw. Multiply those together for each game and add them all up.
+pub fn sum_powers(games: &[Game]) -> u32 {
+    let mut total = 0;
+
+    for game in games {
+        let mut red_max = 0;
+        let mut green_max = 0;
+        let mut blue_max = 0;
+
+        for draw in game {
+            for (number, color) in draw {
+                match color.as_str() {
+                    "red" => {
+                        if *number > red_max {
+                         red_max = *number;
+                        }
+                    },
+
+                    "green" => {
+                        if *number > green_max {
+                         green_max = *number;
+                        }
+                    },
+
+                    "blue" => {
+                        if *number > blue_max {
+                         blue_max = *number;
+                        }
+                    },
+
+                    _ => continue,
+                }
+            }
+        }
+        total += red_max * green_max * blue_max;
+    }
+
+    total
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let games = Day02.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(sum_possible_games(&games), 8);
+        assert_eq!(sum_powers(&games), 2286);
+        Ok(())
+    }
+}
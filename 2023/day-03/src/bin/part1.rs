@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_03::Day03;
+
+fn main() -> ExitCode {
+    run_solution::<Day03>(1)
+}
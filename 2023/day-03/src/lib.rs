@@ -1,22 +1,82 @@
-use std::fs::File;
-use std::io::{ self, prelude::*, BufReader };
+use aoc_helpers::{ * };
+use std::fmt::Display;
 
-const ROWS: usize = 140;
-const COLS: usize = 140;
+#[derive(Default)]
+pub struct Day03;
 
-fn main() -> io::Result<()> {
-    let file = File::open("./input.txt")?;
-    let reader = BufReader::new(file);
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<char>>;
 
-    let mut input: Vec<_> = vec![];
-    let mut total: u32 = 0;
-    
     // read each line into a vector of vectors of characters
-    for line in reader.lines() {
-        let chars: Vec<char> = line?.chars().collect();
-        input.push(chars);
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_part_numbers(input))
     }
 
+    fn part2(&self, input: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_gear_ratios(input))
+    }
+}
+
+pub fn sum_part_numbers(input: &[Vec<char>]) -> u32 {
+    let mut total: u32 = 0;
+
+    // outer loop iterates through the lines
+    for (row, line) in input.iter().enumerate() {
+
+        // this will hold each number we come across
+        let mut num_str: String = "".to_string();
+
+        // set to true if the current number is found to be a part number
+        let mut is_part = false;
+
+        // inner loop iterates through the characters in each line
+        for (col, ch) in line.iter().enumerate() {
+
+            // if the current character is not a number, we skip it
+            if ch.is_numeric() {
+
+                // append the current digit to the number string
+                num_str += &ch.to_string();
+
+                // if we haven't yet determined that the current number is a part number, check the surrounding characters to see if they contain a symbol
+                if !is_part {
+                    let surrounding = get_surrounding(input, row, col);
+                    if contains_symbol(surrounding) { 
+                        is_part = true;
+                    }
+                }
+
+                // if we've reached the end of the line, or the next character is NOT a number, we've finished reading the current number
+                if col == line.len() - 1 || !line[col + 1].is_numeric() {
+
+                    // if we've determined this is a part number, parse it from a string to u32 and add it to the running total
+                    if is_part {
+                        let num = num_str.parse::<u32>().expect("Failed to parse part number");
+                        total += num;
+                        is_part = false;
+                    }
+
+                    // reset the number string for the next one
+                    num_str = "".to_string();
+                }
+            }
+        }
+    }
+
+    total
+}
+
+pub fn sum_gear_ratios(input: &[Vec<char>]) -> u32 {
+    let mut total: u32 = 0;
+    let cols = input.first().map_or(0, |line| line.len());
+
     // outer loop iterates through the lines
     for (row, line) in input.iter().enumerate() {
 
@@ -28,7 +88,7 @@ fn main() -> io::Result<()> {
 
                 // Get a vector of bools that indicate whether the squares surrounding 
                 // the current square contain a number
-                let surrounding = get_surrounding(&input, row, col);
+                let surrounding = get_gear_surrounding(input, row, col);
 
                 // If there are no numbers at all, skip it, this isn't a gear
                 if !surrounding.contains(&true) { continue; }
@@ -41,24 +101,18 @@ fn main() -> io::Result<()> {
                 
                 // If there are no digits directly above or below the *, we're not dealing with the
                 // case we just talked about, so just check for multiple digits in the surroundings
-                if !surrounding[1] && !surrounding[6] {
-                    if surrounding.iter().filter(|&t| *t == true).count() > 1 {
-                        is_gear = true;
-                    }
+                if !surrounding[1] && !surrounding[6] && surrounding.iter().filter(|&t| *t).count() > 1 {
+                    is_gear = true;
                 }
 
                 // If the space directly above or below the * contains a digit, we ignore the other 
                 // spaces above or below the * (because they can't be part of a separate number) and
                 // check for at least one digit in the remaining spaces 
-                if surrounding[1] {
-                    if surrounding[3..].contains(&true) {
-                        is_gear = true;
-                    }
+                if surrounding[1] && surrounding[3..].contains(&true) {
+                    is_gear = true;
                 }
-                if surrounding[6] {
-                    if surrounding[..4].contains(&true) {
-                        is_gear = true;
-                    }
+                if surrounding[6] && surrounding[..4].contains(&true) {
+                    is_gear = true;
                 }
 
                 // Now it gets awkward and cumbersome. Again -- definitely a better way to do this,
@@ -82,11 +136,11 @@ fn main() -> io::Result<()> {
                     // until you hit a non-numeric character, then parse it into an integer and
                     // multiply it by num to get the result
                         while input[row - 1][i].is_numeric() {
-                            num_str.insert_str(0, &input[row - 1][i].to_string());
+                            num_str.insert(0, input[row - 1][i]);
                             if i == 0 { break; }
                             i -= 1;
                         }
-                        while j < COLS && input[row - 1][j].is_numeric() {
+                        while j < cols && input[row - 1][j].is_numeric() {
                             num_str.push(input[row - 1][j]);
                             j += 1;
                         }
@@ -99,7 +153,7 @@ fn main() -> io::Result<()> {
                             num_str = "".to_string();
                             i = col - 1;
                             while input[row - 1][i].is_numeric() {
-                                num_str.insert_str(0, &input[row - 1][i].to_string());
+                                num_str.insert(0, input[row - 1][i]);
                                 if i == 0 { break; }
                                 i -= 1;
                             }
@@ -108,7 +162,7 @@ fn main() -> io::Result<()> {
                         if surrounding[2] {
                             num_str = "".to_string();
                             j = col + 1;
-                            while j < COLS && input[row - 1][j].is_numeric() {
+                            while j < cols && input[row - 1][j].is_numeric() {
                                 num_str.push(input[row - 1][j]);
                                 j += 1;
                             }
@@ -123,11 +177,11 @@ fn main() -> io::Result<()> {
                         j = col + 1;
 
                         while input[row + 1][i].is_numeric() {
-                            num_str.insert_str(0, &input[row + 1][i].to_string());
+                            num_str.insert(0, input[row + 1][i]);
                             if i == 0 { break; }
                             i -= 1;
                         }
-                        while j < COLS && input[row + 1][j].is_numeric() {
+                        while j < cols && input[row + 1][j].is_numeric() {
                             num_str.push(input[row + 1][j]);
                             j += 1;
                         }
@@ -138,7 +192,7 @@ fn main() -> io::Result<()> {
                             num_str = "".to_string();
                             i = col - 1;
                             while input[row + 1][i].is_numeric() {
-                                num_str.insert_str(0, &input[row + 1][i].to_string());
+                                num_str.insert(0, input[row + 1][i]);
                                 if i == 0 { break; }
                                 i -= 1;
                             }
@@ -147,7 +201,7 @@ fn main() -> io::Result<()> {
                         if surrounding[7] {
                             num_str = "".to_string();
                             j = col + 1;
-                            while j < COLS && input[row + 1][j].is_numeric() {
+                            while j < cols && input[row + 1][j].is_numeric() {
                                 num_str.push(input[row + 1][j]);
                                 j += 1;
                             }
@@ -160,7 +214,7 @@ fn main() -> io::Result<()> {
                         num_str = "".to_string();
                         i = col - 1;
                         while line[i].is_numeric() {
-                            num_str.insert_str(0, &line[i].to_string());
+                            num_str.insert(0, line[i]);
                             if i == 0 { break; }
                             i -= 1;
                         }
@@ -170,7 +224,7 @@ fn main() -> io::Result<()> {
                     if surrounding[4] {
                         num_str = "".to_string();
                         j = col + 1;
-                        while j < COLS && line[j].is_numeric() {
+                        while j < cols && line[j].is_numeric() {
                             num_str.push(line[j]);
                             j += 1;
                         }
@@ -183,15 +237,24 @@ fn main() -> io::Result<()> {
         }
     }
 
-    println!("{}", total);
+    total
+}
+
+// checks a given vector to see if it contains a symbol
+fn contains_symbol(chars: Vec<char>) -> bool {
+    for ch in chars {
+        if !ch.is_numeric() && ch != '.' { return true; }
+    }
 
-    Ok(())
+    false
 }
 
 // get the characters surrounding the current index
-fn get_surrounding(input: &Vec<Vec<char>>, row: usize, col: usize) -> Vec<bool> {
+fn get_surrounding(input: &[Vec<char>], row: usize, col: usize) -> Vec<char> {
+    let rows = input.len();
+    let cols = input[0].len();
 
-    let deltas = vec![-1, 0, 1];
+    let deltas = [-1, 0, 1];
     let mut surrounding = Vec::new();
 
     // check the indices -1, +0, and +1 from the current index
@@ -211,13 +274,52 @@ fn get_surrounding(input: &Vec<Vec<char>>, row: usize, col: usize) -> Vec<bool>
                 None => { continue; }
             };
 
-            if input[r][c].is_numeric() {
-                surrounding.push(true);
-            } else {
-                surrounding.push(false);
+            // ensure we don't try to read past the end of the input
+            if r < rows && c < cols {
+                surrounding.push(input[r][c]);
             }
         }
     }
 
     surrounding
 }
+
+// get whether each of the squares surrounding the current index holds a digit, in reading order
+fn get_gear_surrounding(input: &[Vec<char>], row: usize, col: usize) -> Vec<bool> {
+
+    let deltas = [-1, 0, 1];
+    let mut surrounding = Vec::new();
+
+    // check the indices -1, +0, and +1 from the current index
+    for &i in &deltas {
+        for &j in &deltas {
+
+            // skip the current index
+            if i == 0 && j == 0 { continue; }
+
+            // Anything off the edge of the schematic counts as not a digit, so there are always 8 entries
+            let r = row as isize + i;
+            let c = col as isize + j;
+            let digit = r >= 0 && c >= 0 && input.get(r as usize).and_then(|l| l.get(c as usize)).is_some_and(|ch| ch.is_numeric());
+
+            surrounding.push(digit);
+        }
+    }
+
+    surrounding
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let schematic = Day03.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(sum_part_numbers(&schematic), 4361);
+        assert_eq!(sum_gear_ratios(&schematic), 467835);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_04::Day04;
+
+fn main() -> ExitCode {
+    run_solution::<Day04>(2)
+}
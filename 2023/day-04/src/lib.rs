@@ -0,0 +1,89 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day04;
+
+// The numbers on a scratchcard, and the winning numbers listed after the '|'
+pub struct Card {
+    numbers: Vec<u32>,
+    winners: Vec<u32>,
+}
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+
+    // Parse the input into the numbers on each card and the winning numbers for each game
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, |line| {
+            let (card, winners) = line
+                .split_once('|')
+                .ok_or_else(|| AocError::parse(1, 1, format!("expected a card, got {:?}", line)))?;
+            let (_, numbers) = card
+                .split_once(':')
+                .ok_or_else(|| AocError::parse(1, 1, format!("expected a card number, got {:?}", card)))?;
+
+            Ok(Card { numbers: ints(numbers)?, winners: ints(winners)? })
+        })
+    }
+
+    fn part1(&self, cards: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_points(cards))
+    }
+
+    fn part2(&self, cards: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_cards(cards))
+    }
+}
+
+// Count the number of wins in a game
+fn wins(card: &Card) -> usize {
+    card.numbers.iter().filter(|num| card.winners.contains(num)).count()
+}
+
+pub fn sum_points(cards: &[Card]) -> u32 {
+    let mut total: u32 = 0;
+
+    for card in cards {
+        let wins = wins(card) as u32;
+        if wins == 0 { continue; }
+
+        let two:u32 = 2;
+        total += two.pow(wins - 1);
+    }
+
+    total
+}
+
+pub fn count_cards(cards: &[Card]) -> u32 {
+    let mut num_cards: Vec<u32> = vec![1; cards.len()];
+
+    for (c, card) in cards.iter().enumerate() {
+
+        // For n wins, increment the number of cards for the next n games by the
+        // number of cards held for the current game
+        for i in 1..wins(card) + 1 {
+            num_cards[c + i] += num_cards[c];
+        }
+    }
+
+    num_cards.iter().sum()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let cards = Day04.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(sum_points(&cards), 13);
+        assert_eq!(count_cards(&cards), 30);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_06::Day06;
+
+fn main() -> ExitCode {
+    run_solution::<Day06>(2)
+}
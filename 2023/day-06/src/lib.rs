@@ -0,0 +1,91 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day06;
+
+// The race times and record distances, as they're written after the ':'. Part 1 reads them as separate races
+// and part 2 squashes each line into one big number, so it's easiest to keep them as text until then.
+pub struct Races {
+    times: String,
+    dists: String,
+}
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = Races;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let mut lines = input.lines().map(|line| line.split_once(':').map(|(_, values)| values.to_string()));
+
+        match (lines.next().flatten(), lines.next().flatten()) {
+            (Some(times), Some(dists)) => Ok(Races { times, dists }),
+            _ => Err(AocError::parse(1, 1, "expected a line of times and a line of distances")),
+        }
+    }
+
+    fn part1(&self, races: &Self::Input) -> Result<impl Display, AocError> {
+        multiply_ways_to_win(races)
+    }
+
+    fn part2(&self, races: &Self::Input) -> Result<impl Display, AocError> {
+        ways_to_win_long_race(races)
+    }
+}
+
+pub fn multiply_ways_to_win(races: &Races) -> Result<u64, AocError> {
+    let times: Vec<u64> = ints(&races.times)?;
+    let dists: Vec<u64> = ints(&races.dists)?;
+
+    let mut result = 1;
+
+    for (i, time) in times.iter().enumerate() {
+        result *= ways_to_win(*time, dists[i]);
+    }
+
+    Ok(result)
+}
+
+pub fn ways_to_win_long_race(races: &Races) -> Result<u64, AocError> {
+    let time = squash(&races.times)?;
+    let dist = squash(&races.dists)?;
+
+    Ok(ways_to_win(time, dist))
+}
+
+// The kerning was bad, it's all one number
+fn squash(values: &str) -> Result<u64, AocError> {
+    let mut s = values.to_string();
+    s.retain(|c| !c.is_whitespace());
+    s.parse::<u64>().map_err(|_| AocError::parse(1, 1, format!("expected a number, got {:?}", values)))
+}
+
+// How many whole milliseconds of holding the button beat the record
+pub fn ways_to_win(time: u64, dist: u64) -> u64 {
+    let mut total = 0;
+
+    for holdtime in 1..time {
+        if holdtime * (time - holdtime) > dist {
+            total += 1;
+        }
+    }
+
+    total
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let races = Day06.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(multiply_ways_to_win(&races)?, 288);
+        assert_eq!(ways_to_win_long_race(&races)?, 71503);
+        Ok(())
+    }
+}
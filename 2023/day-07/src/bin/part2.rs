@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_07::Day07;
+
+fn main() -> ExitCode {
+    run_solution::<Day07>(2)
+}
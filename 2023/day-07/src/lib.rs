@@ -1,69 +1,115 @@
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
-use std::path::Path;
-use std::collections::{HashMap, HashSet};
+use aoc_helpers::{ * };
+use std::collections::HashSet;
 use std::cmp::Ordering;
-use lazy_static::lazy_static;
+use std::fmt::Display;
 
 const HAND_SIZE: usize = 5;
 
-// Assigns a value to each card rank for evaluation later
-lazy_static! {
-    static ref CARD_VAL: HashMap<char, u32> = {
-        let mut map = HashMap::new();
-        for ch in '2'..'9' {
-            map.insert(ch, ch.to_digit(10).unwrap());
-        }
-        map.insert('9', 9);
-        map.insert('T', 10);
-        map.insert('J', 1);     // in part 2, J has the lowest rank
-        map.insert('Q', 12);
-        map.insert('K', 13);
-        map.insert('A', 14);
-        map    
-    };
-}
+#[derive(Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    // Each hand and its bid
+    type Input = Vec<(String, u64)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, |line| {
+            let (hand, bid) = line
+                .split_once(' ')
+                .ok_or_else(|| AocError::parse(1, 1, format!("expected a hand and a bid, got {:?}", line)))?;
+            let bid = bid
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| AocError::parse(1, 1, format!("bad bid: {:?}", bid)))?;
 
-fn main() {
+            if hand.chars().count() != HAND_SIZE {
+                return Err(AocError::parse(1, 1, format!("expected {} cards, got {:?}", HAND_SIZE, hand)));
+            }
+
+            Ok((hand.to_string(), bid))
+        })
+    }
 
-    let input = get_input("./input.txt");
-    let mut hands: Vec<Vec<String>> = Vec::new();
-    for line in input {
-        let hand: Vec<String> = line.split_whitespace().map(|s| s.to_string()).collect();
-        hands.push(hand);
+    fn part1(&self, hands: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(total_winnings(hands, false))
     }
 
-    hands.sort_by(|a, b| compare_cards(&a[0], &b[0]));
+    fn part2(&self, hands: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(total_winnings(hands, true))
+    }
+}
+
+// Ranks the hands from weakest to strongest and adds up each bid times its rank. In part 2 the J cards are
+// jokers instead of jacks.
+pub fn total_winnings(hands: &[(String, u64)], jokers: bool) -> u64 {
+    let mut hands: Vec<&(String, u64)> = hands.iter().collect();
+    hands.sort_by(|a, b| compare_cards(&a.0, &b.0, jokers));
 
     let mut total = 0;
-    for (i, hand) in hands.iter().enumerate() {
-        let value = hand[1].parse::<u64>().unwrap();
+    for (i, (_, value)) in hands.iter().enumerate() {
         total += value * ((i + 1) as u64);
     }
 
-    println!("{}", total);
+    total
 }
 
+// Assigns a value to each card rank for evaluation later
+fn card_value(card: char, jokers: bool) -> u32 {
+    match card {
+        'T' => 10,
+        'J' if jokers => 1,     // in part 2, J has the lowest rank
+        'J' => 11,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        _ => card.to_digit(10).unwrap_or(0),
+    }
+}
 
-fn get_input(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("File doesn't exist");
-    let buf = BufReader::new(file);
-    buf.lines().map(|l| l.expect("Failed to parse line")).collect()
+fn compare_cards(h1: &str, h2: &str, jokers: bool) -> Ordering {
+    let evaluate = if jokers { evaluate_hand_with_jokers } else { evaluate_hand };
+    let v1 = evaluate(h1);
+    let v2 = evaluate(h2);
+
+    v1.cmp(&v2).then_with(|| check_same_type(h1, h2, jokers))
 }
 
-fn compare_cards(h1: &str, h2: &str) -> Ordering {
-    let v1 = evaluate_hand(h1);
-    let v2 = evaluate_hand(h2);
-    
-    if v1 > v2 { return Ordering::Greater; }
-    else if v1 < v2 { return Ordering::Less; }
-    else {
-        return check_same_type(h1, h2);
+// Evaluates a hand by its type
+fn evaluate_hand(hand: &str) -> u8 {
+    let unique_cards = count_unique_cards(hand);
+
+    if unique_cards == 1 {
+        return 7;               // 5 of a kind
+    } else if unique_cards == 2 {
+        let first_count = hand.chars().filter(|&c| c == hand.chars().next().unwrap()).count();
+        if first_count == 1 || first_count == 4 {
+            return 6;           // 4 of a kind
+        } else {
+            return 5;           // full house
+        }
+    } else if unique_cards == 3 {   
+        for i in 0..HAND_SIZE {
+            let count = hand.chars().filter(|&c| c == hand.chars().nth(i).unwrap()).count();
+            if count == 1 { continue; }
+            if count == 2 {
+                return 3;       // 2 pair
+            } else if count == 3 {
+                return 4;       // 3 of a kind
+            }
+        }
+    } else if unique_cards == 4 {
+        return 2;               // 1 pair
+    } else {
+        return 1;               // high card
     }
+    0
 }
 
 // Evaluates a hand by its type, accounting for wildcards
-fn evaluate_hand(hand: &str) -> u8 {
+fn evaluate_hand_with_jokers(hand: &str) -> u8 {
     let count_j = hand.chars().filter(|&c| c == 'J').count();
 
     // Get the hand's type by checking the number of unique cards in the hand
@@ -76,7 +122,7 @@ fn evaluate_hand(hand: &str) -> u8 {
     // If there are 2 unique cards, it's either 4 of a kind or a full house -- we have
     // to count the occurrences of any one card to find out which. We'll use the first for simplicity.
     } else if unique_cards == 2 {
-        let first_count = hand.chars().filter(|&c| c == hand.chars().nth(0).unwrap()).count();
+        let first_count = hand.chars().filter(|&c| c == hand.chars().next().unwrap()).count();
         
         // If there are 1 or 4 occurrences of the first card, it's 4 of a kind
         if first_count == 1 || first_count == 4 {
@@ -111,7 +157,7 @@ fn evaluate_hand(hand: &str) -> u8 {
                 if count_j == 0 {
                     return 3;       // 2 pair
 
-                // 
+                // A wildcard or two upgrades it depending on how many there are
                 } else {
                     match count_j {
                         1 => return 5,    // 1 wildcard makes this a full house
@@ -162,24 +208,32 @@ fn evaluate_hand(hand: &str) -> u8 {
 }
 
 // Evaluates a hand card by card if they have the same type
-fn check_same_type(h1: &str, h2: &str) -> Ordering {
-    for i in 0..HAND_SIZE {
-        if h1.chars().nth(i) == h2.chars().nth(i) { 
-            continue; 
-        }
-        let v1 = CARD_VAL.get(&h1.chars().nth(i).unwrap());
-        let v2 = CARD_VAL.get(&h2.chars().nth(i).unwrap());
-        if v1 > v2 {
-            return Ordering::Greater;
-        } else {
-            return Ordering::Less;
+fn check_same_type(h1: &str, h2: &str, jokers: bool) -> Ordering {
+    for (c1, c2) in h1.chars().zip(h2.chars()) {
+        if c1 == c2 {
+            continue;
         }
+        return card_value(c1, jokers).cmp(&card_value(c2, jokers));
     }
-    println!("uh oh");
     Ordering::Equal
 }
 
 fn count_unique_cards(input: &str) -> usize {
     let unique_cards: HashSet<char> = input.chars().collect();
     unique_cards.len()
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let hands = Day07.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(total_winnings(&hands, false), 6440);
+        assert_eq!(total_winnings(&hands, true), 5905);
+        Ok(())
+    }
+}
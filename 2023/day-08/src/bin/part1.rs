@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_08::Day08;
+
+fn main() -> ExitCode {
+    run_solution::<Day08>(1)
+}
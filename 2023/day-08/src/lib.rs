@@ -0,0 +1,106 @@
+use aoc_helpers::{ * };
+use std::collections::HashMap;
+use std::fmt::Display;
+
+const START_NODE: &str = "AAA";
+const END_NODE: &str = "ZZZ";
+
+#[derive(Default)]
+pub struct Day08;
+
+// The left/right instructions and the graph, with each node's left and right neighbours
+pub struct Network {
+    sequence: String,
+    graph: HashMap<String, (String, String)>,
+}
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let (sequence, nodes) = input
+            .split_once("\n\n")
+            .ok_or_else(|| AocError::parse(1, 1, "missing blank line between the instructions and the nodes"))?;
+
+        // Parse the graph from the input lines, which start after the instructions and the blank line
+        let pattern = Pattern::new("{} = ({}, {})");
+        let graph = nodes
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (node, left, right) = pattern.parse(line).map_err(|e| on_line(e, i + 3))?;
+                Ok((node, (left, right)))
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        Ok(Network { sequence: sequence.trim().to_string(), graph })
+    }
+
+    fn part1(&self, network: &Self::Input) -> Result<impl Display, AocError> {
+        if !network.graph.contains_key(START_NODE) {
+            return Err(AocError::parse(1, 1, format!("there's no {} node", START_NODE)));
+        }
+
+        Ok(steps_to_end(network, START_NODE, |node| node == END_NODE))
+    }
+
+    fn part2(&self, network: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(ghost_steps(network))
+    }
+}
+
+// Follows the instructions from `start`, round and round, until we hit a node `is_end` is happy with
+pub fn steps_to_end(network: &Network, start: &str, is_end: impl Fn(&str) -> bool) -> u64 {
+    let mut steps = 0;
+    let mut node = start;
+
+    // Iterate through the graph until we reach the end node
+    for ch in network.sequence.chars().cycle() {
+        steps += 1;
+
+        let (left, right) = &network.graph[node];
+        match ch {
+            'L' => node = left,
+            'R' => node = right,
+            _ => continue,
+        };
+        if is_end(node) {
+            break;
+        }
+    }
+
+    steps
+}
+
+// Every node ending in A starts a path. Find the number of steps before each path reaches a node ending in Z,
+// then the LCM of those is the step count when all paths will reach Z-nodes at the same time.
+pub fn ghost_steps(network: &Network) -> u64 {
+    let first_z = network
+        .graph
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(|node| steps_to_end(network, node, |n| n.ends_with('Z')));
+
+    lcm_all(first_z)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let first = Day08.parse(&read_from_file_as_string("example1.txt")?)?;
+        let second = Day08.parse(&read_from_file_as_string("example2.txt")?)?;
+        let ghosts = Day08.parse(&read_from_file_as_string("example3.txt")?)?;
+
+        assert_eq!(steps_to_end(&first, START_NODE, |n| n == END_NODE), 2);
+        assert_eq!(steps_to_end(&second, START_NODE, |n| n == END_NODE), 6);
+        assert_eq!(ghost_steps(&ghosts), 6);
+        Ok(())
+    }
+}
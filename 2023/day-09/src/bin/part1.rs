@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_09::Day09;
+
+fn main() -> ExitCode {
+    run_solution::<Day09>(1)
+}
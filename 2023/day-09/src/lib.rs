@@ -0,0 +1,107 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, ints)
+    }
+
+    fn part1(&self, histories: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_next_values(histories))
+    }
+
+    fn part2(&self, histories: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_previous_values(histories))
+    }
+}
+
+// Parse the current history into a list of sequences, each one the differences between the entries of the
+// one before, until we get to a sequence of all zeros
+fn sequences(hist: &[i32]) -> Vec<Vec<i32>> {
+    let mut predictions: Vec<Vec<i32>> = vec![hist.to_vec()];
+    let mut current: Vec<i32> = hist.to_vec();
+
+    while !current.iter().all(|&x| x == 0) {
+        let mut next: Vec<i32> = vec![];
+
+        for (i, entry) in current[..current.len() - 1].iter().enumerate() {
+            next.push(current[i + 1] - entry);
+        }
+        predictions.push(next.clone());
+        current = next;
+    }
+
+    predictions
+}
+
+pub fn sum_next_values(histories: &[Vec<i32>]) -> i32 {
+    let mut total = 0;
+
+    for hist in histories {
+        let predictions = sequences(hist);
+
+        // Iterate through the sequences backwards and calculate the addend for each
+        // sequence by adding the previous addend to the last entry in the current line
+        let mut addends: Vec<i32> = vec![0];
+        for line in predictions[..predictions.len() - 1].iter().rev() {
+            let addend = line.last().unwrap() + addends.last().unwrap();
+            addends.push(addend);
+        }
+
+        // Add the addend from the original history to the running total
+        total += addends.last().unwrap();
+    }
+
+    total
+}
+
+pub fn sum_previous_values(histories: &[Vec<i32>]) -> i32 {
+    let mut total = 0;
+
+    for hist in histories {
+        let predictions = sequences(hist);
+
+        // Reverse the values in the predictions list so we can use the same algorithm from part 1
+        // (subtracting rather than adding)
+        let mut pred_rev: Vec<Vec<i32>> = vec![];
+        for entry in predictions {
+            pred_rev.push(entry.iter().rev().cloned().collect());
+        }
+
+        // Iterate through the sequences backwards and calculate the addend for each
+        // sequence by subtracting the previous addend from the last entry in the current line
+        let mut addends: Vec<i32> = vec![0];
+        for line in pred_rev[..pred_rev.len() - 1].iter().rev() {
+            let addend = line.last().unwrap() - addends.last().unwrap();
+            addends.push(addend);
+        }
+
+        // Adding the addend from the original history to the running total
+        total += addends.last().unwrap();
+    }
+
+    total
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let histories = Day09.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(sum_next_values(&histories), 114);
+        assert_eq!(sum_previous_values(&histories), 2);
+        Ok(())
+    }
+}
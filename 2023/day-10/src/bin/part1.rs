@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_10::Day10;
+
+fn main() -> ExitCode {
+    run_solution::<Day10>(1)
+}
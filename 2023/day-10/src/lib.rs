@@ -0,0 +1,204 @@
+use aoc_helpers::{ * };
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/* 
+Well, this one almost beat me. After hours of research and bashing my head against the wall, I was so close -- I had (mostly) figured out the ray casting algorithm, 
+I got the correct answer on two of the four test cases, but I couldn't figure out how the hell to handle the "squeeze-through" problem. Not ashamed to admit I got
+some help on this one to correct my algorithm. Instead of toggling the ray casting boolean every time I hit a pipe segment perpendicular to the direction of traversal
+like I had been doing, I had to toggle only on pipe segments with a north-facing side (for traversal to the east) -- '|', 'L', or 'J'. I still don't quite understand
+why that works, but it does, and my noob self is just proud to have gotten this far with this amount of help. 
+*/
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let mut map = Map::new(input.lines().map(|line| line.chars().collect()).collect())?;
+        map.find_loop()?;
+        Ok(map)
+    }
+
+    // The point farthest from the start is halfway round the loop, whichever way you go
+    fn part1(&self, map: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(map.loop_pipes.len() / 2)
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(map.cast_ray())
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    data: Vec<Vec<char>>,
+    start_pos: (i32, i32),
+    loop_pipes: HashSet<(i32, i32)>,
+    s_shape: char,
+    rows: usize,
+    cols: usize,
+}
+
+impl Map {
+    fn new(data: Vec<Vec<char>>) -> Result<Self, AocError> {
+        let rows = data.len();
+        let cols = data.first().map_or(0, |row| row.len());
+
+        let start_pos = data
+            .iter()
+            .enumerate()
+            .find_map(|(y, line)| line.iter().position(|&ch| ch == 'S').map(|x| (x as i32, y as i32)))
+            .ok_or_else(|| AocError::parse(1, 1, "there's no S on the map"))?;
+
+        Ok(Map { data, start_pos, loop_pipes: HashSet::new(), s_shape: '_', rows, cols })
+    }
+
+    // Returns the pipe at a given (x, y) position within the map
+    fn get_pipe(&self, pos: (i32, i32)) -> Option<char> {
+        self.data.get(usize::try_from(pos.1).ok()?)?.get(usize::try_from(pos.0).ok()?).copied()
+    }
+
+    // Attempts to traverse the loop in the given direction. Returns
+    // the pipes along the way if that direction is valid and the loop can be traversed
+    fn traverse(&self, start_dir: Dir4) -> Option<Vec<(i32, i32)>> {
+        let mut dir = start_dir;
+        let mut pos = self.start_pos;
+        let mut pipe_list: Vec<(i32, i32)> = vec![];
+
+        // Loop until we return to the start node or we reach
+        // a dead end (or fall off the edge of the map)
+        loop {
+            pos = dir.step(pos);
+
+            let pipe = self.get_pipe(pos)?;
+            pipe_list.push(pos);
+
+            if pipe == 'S' {
+                return Some(pipe_list);
+            }
+
+            dir = next_dir(dir, pipe)?;
+        }
+    }
+
+    // Finds the loop through the start, and the shape of the pipe hiding under the S
+    fn find_loop(&mut self) -> Result<(), AocError> {
+        let mut start_dirs: Vec<Dir4> = vec![];
+
+        // Attempts to traverse the loop in all four directions, recording which
+        // directions are valid for later use
+        for direction in Dir4::ALL {
+            if let Some(pipes) = self.traverse(direction) {
+                self.loop_pipes = pipes.into_iter().collect();
+                start_dirs.push(direction);
+            }
+        }
+
+        if start_dirs.is_empty() {
+            return Err(AocError::parse(self.start_pos.1 as usize + 1, self.start_pos.0 as usize + 1, "the S isn't part of a loop"));
+        }
+
+        // Determine the shape of the starting pipe based on the two valid
+        // directions of travel leaving that pipe.
+        // We need this for the ray casting algorithm, because whether a node is
+        // enclosed within the loop depends on the shapes of the pipes around it.
+        if start_dirs.contains(&Dir4::N) && start_dirs.contains(&Dir4::S) {
+            self.s_shape = '|';
+        } else if start_dirs.contains(&Dir4::N) && start_dirs.contains(&Dir4::E) {
+            self.s_shape = 'L';
+        } else if start_dirs.contains(&Dir4::N) && start_dirs.contains(&Dir4::W) {
+            self.s_shape = 'J';
+        } else if start_dirs.contains(&Dir4::S) && start_dirs.contains(&Dir4::E) {
+            self.s_shape = 'F';
+        } else if start_dirs.contains(&Dir4::S) && start_dirs.contains(&Dir4::W) {
+            self.s_shape = '7';
+        } else if start_dirs.contains(&Dir4::E) && start_dirs.contains(&Dir4::W) {
+            self.s_shape = '-';
+        }
+
+        Ok(())
+    }
+
+    // Ray casting algorithm. Iterates across the map, toggling a bool any time it
+    // reaches a pipe segment that's both part of the loop and has a valid connection
+    // facing north ('|', 'L', or 'J'). Crossing any of those means we've either
+    // entered or exited an enclosed section of the loop. Any position we reach
+    // while is_enclosed == true is enclosed.
+
+    // Full disclosure: I had to look up that last part of the solution. I was so close
+    // with my ray casting algorithm, but I was toggling every time I reached any
+    // pipe segment that was part of the loop and perpendicular to the direction of
+    // traversal. Couldn't figure out why my answers were (usually) wrong until I 
+    // got some help. 
+    fn cast_ray(&self) -> u32 {
+        let mut is_enclosed = false;
+
+        let mut enclosed = 0;
+
+        for y in 0..self.rows {
+            for x in 0..self.cols {
+                let pos = (x as i32, y as i32);
+                let valid_pipes = ['|', 'L', 'J'];
+
+                // If the segment we're checking is the start position ('S'), change
+                // it the correct shape based on the valid traversal directions as
+                // we calculated earlier
+                let mut pipe = self.get_pipe(pos).unwrap_or('.');
+                if pipe == 'S' {
+                    pipe = self.s_shape;
+                }
+
+                // If the current segment is both contained within the loop and has
+                // the correct shape, toggle is_enclosed and move on
+                if self.loop_pipes.contains(&pos) {
+                    if valid_pipes.contains(&pipe) {
+                        is_enclosed = !is_enclosed;
+                    }
+                    continue;
+                }
+
+                if is_enclosed {
+                    enclosed += 1;
+                }
+            }
+        }
+        enclosed
+    }
+}
+
+// Returns the next direction given the entry direction
+// and the current pipe shape
+fn next_dir(dir: Dir4, pipe: char) -> Option<Dir4> {
+    match (pipe, dir) {
+        ('|', Dir4::N | Dir4::S) | ('-', Dir4::E | Dir4::W) => Some(dir),
+        ('L', Dir4::S) | ('J', Dir4::E) | ('7', Dir4::N) | ('F', Dir4::W) => Some(dir.turn_left()),
+        ('L', Dir4::W) | ('J', Dir4::S) | ('7', Dir4::E) | ('F', Dir4::N) => Some(dir.turn_right()),
+        _ => None,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let farthest = Day10.parse(&read_from_file_as_string("example1.txt")?)?;
+        let simple = Day10.parse(&read_from_file_as_string("example2.txt")?)?;
+        let squeezed = Day10.parse(&read_from_file_as_string("example3.txt")?)?;
+        let junk = Day10.parse(&read_from_file_as_string("example4.txt")?)?;
+
+        assert_eq!(farthest.loop_pipes.len() / 2, 8);
+        assert_eq!(simple.cast_ray(), 4);
+        assert_eq!(squeezed.cast_ray(), 8);
+        assert_eq!(junk.cast_ray(), 10);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_11::Day11;
+
+fn main() -> ExitCode {
+    run_solution::<Day11>(1)
+}
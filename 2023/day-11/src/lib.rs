@@ -1,22 +1,65 @@
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
-use std::path::Path;
+use aoc_helpers::{ * };
+use std::fmt::Display;
 
 /*
 I spent an embarrasing amount of time on this puzzle, trying to implement a BFS algorithm to measure distance between the two
 points. I finally realized that these are just Cartesian points and we can just take the Manhattan distance (|x2 - x1| + |y2 - y1|)
 to get the distance. It works a lot faster now. Imagine that.
 
-This is my solution to both parts. For part 1, set EXPANSION_RATE to 2, and for part 2, set it to 1000000.
+The only difference between the parts is how much each empty row and column expands: twice as big in part 1, and
+a million times as big in part 2.
 */
 
 
-// Each empty row and column is multiplied by the expansion rate
-const EXPANSION_RATE: usize = 1000000;
+pub struct Day11 {
+
+    // How many times bigger each empty row and column gets in part 2
+    pub expansion: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self { expansion: 1000000 }
+    }
+}
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Input = Map;
+
+    // The puzzle walks through the example expanding by 10 and 100 rather than a million, so that defaults to
+    // 100. Either can be overridden with '--set expansion=N'.
+    fn from_args(args: &RunArgs) -> Result<Self, AocError> {
+        Ok(Self { expansion: args.param("expansion", 100, 1000000)? })
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let data: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+
+        if data.iter().any(|row| row.len() != data[0].len()) {
+            return Err(AocError::parse(1, 1, "the rows of the image aren't all the same length"));
+        }
+
+        let mut map = Map::new(data);
+        map.expand_space();
+        map.find_galaxies();
+        Ok(map)
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(map.get_total_distance(2))
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(map.get_total_distance(self.expansion))
+    }
+}
 
 #[derive(Debug)]
-struct Map {
-    data: Vec<String>,
+pub struct Map {
+    data: Vec<Vec<char>>,
     rows: usize,
     cols: usize,
     galaxies: Vec<(usize, usize)>,
@@ -24,11 +67,11 @@ struct Map {
     empty_cols: Vec<usize>,
 }
 
-impl<'a> Map {
-    fn new(data: Vec<String>) -> Self {
+impl Map {
+    fn new(data: Vec<Vec<char>>) -> Self {
 
         let rows = data.len();
-        let cols = data[0].len();
+        let cols = data.first().map_or(0, |row| row.len());
 
         let empty_rows: Vec<usize> = vec![];
         let empty_cols: Vec<usize> = vec![];
@@ -39,11 +82,8 @@ impl<'a> Map {
     }
 
 
-    // Returns the total distance across all pairs
-    fn get_total_distance(&mut self) -> usize {
-        self.expand_space();
-        self.find_galaxies();
-
+    // Returns the total distance across all pairs, with each empty row and column multiplied by the expansion rate
+    pub fn get_total_distance(&self, expansion_rate: usize) -> usize {
         let mut dist: usize = 0;
 
 
@@ -57,20 +97,20 @@ impl<'a> Map {
                 // x direction is increased by the expansion rate
                 for col in &self.empty_cols {
                     if self.galaxies[i].0 < *col && self.galaxies[j].0 > *col {
-                        p2.0 += EXPANSION_RATE - 1;
+                        p2.0 += expansion_rate - 1;
 
                     } else if self.galaxies[i].0 > *col && self.galaxies[j].0 < *col {
-                        p1.0 += EXPANSION_RATE - 1;
+                        p1.0 += expansion_rate - 1;
                     }
                 }
 
                 // Same with rows, but in the y direction
                 for row in &self.empty_rows {
                     if self.galaxies[i].1 < *row && self.galaxies[j].1 > *row {
-                        p2.1 += EXPANSION_RATE - 1;
-                        
+                        p2.1 += expansion_rate - 1;
+
                      } else if self.galaxies[i].1 > *row && self.galaxies[j].1 < *row {
-                        p1.1 += EXPANSION_RATE - 1;
+                        p1.1 += expansion_rate - 1;
                     }
                 }
 
@@ -85,10 +125,7 @@ impl<'a> Map {
     // This function was WAY more complicated when I was trying to implement a BFS
     // algorithm to measure the distance between these two points... whoops
     fn measure_distance(&self, p1: (usize, usize), p2: (usize, usize)) -> usize {
-        let x_diff = if p1.0 > p2.0 {p1.0 - p2.0} else {p2.0 - p1.0};
-        let y_diff = if p1.1 > p2.1 {p1.1 - p2.1} else {p2.1 - p1.1};
-
-        x_diff + y_diff
+        p1.0.abs_diff(p2.0) + p1.1.abs_diff(p2.1)
     }
 
 
@@ -100,8 +137,7 @@ impl<'a> Map {
         assert!(x < self.cols, "x out of bounds");
         assert!(y < self.rows, "y out of bounds");
 
-        let row = self.data.get(y);
-        row.expect("No such position").chars().nth(x) == Some('#')
+        self.data[y][x] == '#'
     }
 
 
@@ -170,16 +206,18 @@ impl<'a> Map {
 
 }
 
-fn main() {
-    let input = get_input("./input.txt");
-    let mut map = Map::new(input.clone());
 
-    println!("{}", map.get_total_distance());
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let map = Day11::default().parse(&read_from_file_as_string("example.txt")?)?;
 
-fn get_input(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("File not found");
-    let buf = BufReader::new(file);
-    buf.lines().map(|l| l.expect("Failed to parse line")).collect()
+        assert_eq!(map.get_total_distance(2), 374);
+        assert_eq!(map.get_total_distance(10), 1030);
+        assert_eq!(map.get_total_distance(100), 8410);
+        Ok(())
+    }
 }
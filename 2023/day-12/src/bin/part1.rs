@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_12::Day12;
+
+fn main() -> ExitCode {
+    run_solution::<Day12>(1)
+}
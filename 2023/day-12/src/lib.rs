@@ -0,0 +1,123 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+/* 
+Wow. This was a trip. After a ton of research about dynamic programming and memoization, numerous solve attempts that were either completely
+wrong or were on track to take multiple days to complete, and a bunch of bashing my face against the keyboard, this one is done. This solution
+walks through the row one spring at a time, recursively working out the possible valid arrangements from any given point in the row to the end
+of it. Rather than attempting to bruteforce this and evaluating every possible combination, the answer from each point gets cached along with
+how many groups are still to be placed and how long the current run of #'s is. Then, when we come across the same point again in the future,
+we can just pull its value out of the cache instead of having to recursively calculate it over again. Pretty classic DP stuff, and it took my
+execution time from >24 hours down to less than a second. 
+
+I can't take full credit for this one -- I got some general algorithm advice from the geniuses on the AoC subreddit, but as a pretty n00by 
+programmer, I'm still super proud of this solve.
+
+This solution works for parts 1 and 2. Part 2 just unfolds each row first.
+*/
+
+#[derive(Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    // Each row of springs and the sizes of its groups of damaged springs
+    type Input = Vec<(String, Vec<usize>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, parse_input)
+    }
+
+    fn part1(&self, rows: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(rows.iter().map(|(springs, arrangement)| count_arrangements(springs, arrangement)).sum::<usize>())
+    }
+
+    fn part2(&self, rows: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(rows
+            .iter()
+            .map(|(springs, arrangement)| {
+                let (springs, arrangement) = unfold(springs, arrangement);
+                count_arrangements(&springs, &arrangement)
+            })
+            .sum::<usize>())
+    }
+}
+
+// The number of ways the unknown springs in a row could be filled in to match its arrangement. The key is how far
+// into the row we are, how many groups are still to be placed, and how many #'s the group we're in the middle of
+// has so far. The row and the arrangement don't change, so they're just captured.
+pub fn count_arrangements(springs: &str, arrangement: &[usize]) -> usize {
+    let springs = springs.as_bytes();
+
+    memoize((0, arrangement.len(), 0), |&(offset, groups_left, run): &(usize, usize, usize), recurse| {
+        let group = arrangement.len() - groups_left;
+
+        // At the end of the row every group has to be used up, the last one possibly by the run we're still in
+        if offset == springs.len() {
+            return usize::from(groups_left == 0 || (groups_left == 1 && run == arrangement[group]));
+        }
+
+        let mut count = 0;
+
+        // A '#' (or a '?' read as one) carries on the current run, as long as there's a group for it to fit in
+        if springs[offset] != b'.' && groups_left > 0 && run < arrangement[group] {
+            count += recurse((offset + 1, groups_left, run + 1));
+        }
+
+        // A '.' (or a '?' read as one) either keeps us between groups or closes off a run that's exactly the right size
+        if springs[offset] != b'#' {
+            if run == 0 {
+                count += recurse((offset + 1, groups_left, 0));
+            } else if run == arrangement[group] {
+                count += recurse((offset + 1, groups_left - 1, 0));
+            }
+        }
+
+        count
+    })
+}
+
+
+// Parse a row of the input normally for Part 1
+fn parse_input(line: &str) -> Result<(String, Vec<usize>), AocError> {
+    let (springs, arrangement) = line
+        .split_once(' ')
+        .ok_or_else(|| AocError::parse(1, 1, format!("expected springs and group sizes, got {:?}", line)))?;
+
+    Ok((springs.to_string(), ints(arrangement)?))
+}
+
+
+// Unfold a row for Part 2
+fn unfold(springs: &str, arrangement_folded: &[usize]) -> (String, Vec<usize>) {
+    let mut springs = springs.to_string() + "?";
+    springs = springs.repeat(5);
+    springs = springs[..springs.len() - 1].to_string();
+
+    let mut arrangement: Vec<usize> = vec![];
+
+    for _ in 0..5 {
+        for arr in arrangement_folded {
+            arrangement.push(*arr);
+        }
+    }
+    (springs, arrangement)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let rows = Day12.parse(&read_from_file_as_string("example.txt")?)?;
+        let unfolded: Vec<(String, Vec<usize>)> = rows.iter().map(|(s, a)| unfold(s, a)).collect();
+
+        assert_eq!(rows.iter().map(|(s, a)| count_arrangements(s, a)).collect::<Vec<_>>(), vec![1, 4, 1, 1, 4, 10]);
+        assert_eq!(unfolded.iter().map(|(s, a)| count_arrangements(s, a)).sum::<usize>(), 525152);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_13::Day13;
+
+fn main() -> ExitCode {
+    run_solution::<Day13>(1)
+}
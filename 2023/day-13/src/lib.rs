@@ -1,26 +1,44 @@
-use std::fs::File;
-use std::io::{prelude::*, BufReader};
-use std::path::Path;
-use array2d::{Array2D};
+use aoc_helpers::{ * };
+use std::fmt::Display;
 
 /*
 We store the cells in the input as 1s and 0s, so we can do some binary algebra on them to figure out the number of
-differences. For part 1, we needed to find rows/cols that were identical (i.e., with 0 differences), but for part 2, to
+differences. For part 1, we need to find rows/cols that were identical (i.e., with 0 differences), but for part 2, to
 find the smudge, we have to find a set of rows/cols with exactly 1 difference. From there, we do some validation to check
 whether those two rows/cols are part of a mirrored set, and if so, we find the two center rows/cols in that set and return
-the index of the lower of the two. I used the Array2D crate to store the input so I could easily access it by rows or columns.
+the index of the lower of the two. I used a Grid to store the input so I could easily access it by rows or columns.
 */
 
-fn main() {
-    let input: Vec<String> = get_input("./input.txt");
-    let maps = map_input(input);
-    
-    let mut total = 0;
+#[derive(Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Grid<u32>>;
+
+    // Convert the input maps to a vector of Grids, with a blank line between each map
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        blocks(input)
+            .into_iter()
+            .map(|block| {
+                Grid::parse_with(block, |ch| match ch {
+                    '.' => Ok(0),
+                    '#' => Ok(1),
+                    _ => Err(()),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, maps: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(maps.iter().map(find_mirror).sum::<usize>())
+    }
 
-    for map in maps {
-        total += fix_smudge(map);
+    fn part2(&self, maps: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(maps.iter().map(fix_smudge).sum::<usize>())
     }
-    println!("Total: {}", total);
 }
 
 
@@ -38,12 +56,12 @@ fn vec_to_int(arr: Vec<u32>) -> u32 { arr.into_iter().fold(0, |acc, digit| (acc
 // value. This checks rows and then columns, returning as soon as it finds a valid mirror.
 // We also find the original mirror value from part 1 and discard any result that's equal to it,
 // because we know the result in this part will be different.
-fn fix_smudge(map: Array2D<u32>) -> usize {
+pub fn fix_smudge(map: &Grid<u32>) -> usize {
     
-    let original = find_mirror(map.clone());
+    let original = find_mirror(map);
 
     // Try rows first
-    let rows = map.as_rows();
+    let rows: Vec<Vec<u32>> = map.rows().map(|row| row.to_vec()).collect();
 
     for i in 0..rows.len() - 1 {
         for j in i + 1..rows.len() {
@@ -51,7 +69,7 @@ fn fix_smudge(map: Array2D<u32>) -> usize {
             let line2: u32 = vec_to_int(rows[j].clone());
 
             // If there's 1 difference, and this is part of a mirror...
-            if diff(line1, line2) == 1 && check_mirror(rows.clone(), i, j) {
+            if diff(line1, line2) == 1 && check_mirror(&rows, i, j) {
                 let result = 100 * (1 + i + (j - i) / 2);
 
                 // ...and if the result is different from the part 1 result from this input, we have a winner
@@ -63,14 +81,14 @@ fn fix_smudge(map: Array2D<u32>) -> usize {
     }
 
     // Try columns if we didn't find a valid row mirror
-    let cols = map.as_columns();
+    let cols: Vec<Vec<u32>> = map.columns().map(|col| col.copied().collect()).collect();
 
     for i in 0..cols.len() - 1 {
         for j in i + 1..cols.len() {
-            let line1: u32 = cols[i].clone().into_iter().fold(0, |acc, digit| (acc << 1) + digit);
-            let line2: u32 = cols[j].clone().into_iter().fold(0, |acc, digit| (acc << 1) + digit);
+            let line1: u32 = vec_to_int(cols[i].clone());
+            let line2: u32 = vec_to_int(cols[j].clone());
 
-            if diff(line1, line2) == 1 && check_mirror(cols.clone(), i, j) {
+            if diff(line1, line2) == 1 && check_mirror(&cols, i, j) {
                 let result = 1 + i + (j - i) / 2;
                 if result != original {
                     return result;
@@ -82,9 +100,9 @@ fn fix_smudge(map: Array2D<u32>) -> usize {
 }
 
 
-// This is essentially the algorithm from part 1
-fn find_mirror(array: Array2D<u32>) -> usize {
-    let rows = array.as_rows();
+// Finds the line of reflection for part 1, where the rows/cols either side of it have to match exactly
+pub fn find_mirror(array: &Grid<u32>) -> usize {
+    let rows: Vec<&[u32]> = array.rows().collect();
 
     'end: for row in 0..rows.len() - 1 {
         if rows[row] == rows[row + 1] {
@@ -96,14 +114,13 @@ fn find_mirror(array: Array2D<u32>) -> usize {
 
                 if rows[i] != rows[j] {
                     continue 'end;
-                } else {
                 }
             }
             return 100 * (row + 1);
         }
     }
 
-    let cols = array.as_columns();
+    let cols: Vec<Vec<u32>> = array.columns().map(|col| col.copied().collect()).collect();
 
     'end: for col in 0..cols.len() - 1 {
         if cols[col] == cols[col + 1] {
@@ -126,13 +143,13 @@ fn find_mirror(array: Array2D<u32>) -> usize {
 
 // Takes a map (converted to rows or columns) and two indices and checks if these
 // indices are part of a valid mirror
-fn check_mirror(map: Vec<Vec<u32>>, in1: usize, in2: usize) -> bool {
+fn check_mirror(map: &[Vec<u32>], in1: usize, in2: usize) -> bool {
 
     // If the distance between the two indices is even, it can't be a valid mirror.
     // Just trust me, the math checks out.
     let dist = in2 - in1;
     
-    if dist % 2 == 0{
+    if dist.is_multiple_of(2) {
         return false;
     }
 
@@ -185,37 +202,16 @@ fn check_mirror(map: Vec<Vec<u32>>, in1: usize, in2: usize) -> bool {
 }
 
 
-// Convert the input maps to a vector of Array2D's
-fn map_input(input: Vec<String>) -> Vec<Array2D<u32>> {
-    let mut maps: Vec<Array2D<u32>> = Vec::new();
-    let mut bin_line: Vec<u32> = Vec::new();
-    let mut bin_vec: Vec<Vec<u32>> = Vec::new();
-
-    for line in input {
-        if line.is_empty() {
-            if let Ok(map) = Array2D::from_rows(&bin_vec) {
-                maps.push(map);
-                bin_vec = vec![];
-            }
-        } else {
-            for ch in line.chars() {
-                match ch {
-                    '.' => bin_line.push(0),
-                    '#' => bin_line.push(1),
-                    _ => continue,
-                }
-            }
-            bin_vec.push(bin_line);
-            bin_line = vec![];
-        }
-    }
-    maps
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let maps = Day13.parse(&read_from_file_as_string("example.txt")?)?;
 
-// Get the input lines from the input file
-fn get_input(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("File not found");
-    let buf = BufReader::new(file);
-    buf.lines().map(|l| l.expect("Failed to parse line")).collect()
+        assert_eq!(maps.iter().map(find_mirror).collect::<Vec<_>>(), vec![5, 400]);
+        assert_eq!(maps.iter().map(fix_smudge).collect::<Vec<_>>(), vec![300, 100]);
+        Ok(())
+    }
 }
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_14::Day14;
+
+fn main() -> ExitCode {
+    run_solution::<Day14>(1)
+}
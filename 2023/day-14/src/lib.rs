@@ -0,0 +1,195 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+/*
+Set up a struct to handle the map, rock types, movement, etc. Part 1 only requires movement to the north, but I'm anticipating
+movement in other directions in part 2, so I've implemented all 4 directions.
+*/
+
+#[derive(Default)]
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let grid = Grid::parse_with(input, |ch| match ch {
+            '#' => Ok(Rock::Square),
+            'O' => Ok(Rock::Round),
+            '.' => Ok(Rock::Empty),
+            _ => Err(()),
+        })?;
+
+        Ok(Map::new(grid))
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(north_load(map))
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(load_after_spins(map, 1000000000))
+    }
+}
+
+// Tilt the platform north so the round rocks all roll as far north as they can, then weigh them
+pub fn north_load(map: &Map) -> usize {
+    let mut map = map.clone();
+    map.tilt_all(Dir4::N);
+    map.calculate_load()
+}
+
+// A billion spin cycles is far too many to actually run, but the platform settles into a loop long before
+// that, so the cycle helper can skip straight to wherever the loop has got to after the last one
+pub fn load_after_spins(map: &Map, spins: usize) -> usize {
+    let map = state_at(map.clone(), spins, |map| {
+        let mut next = map.clone();
+        next.spin_cycle();
+        next
+    });
+
+    map.calculate_load()
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Rock {
+    Square,
+    Round,
+    Empty,
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Map {
+    grid: Grid<Rock>,
+    rows: usize,
+    cols: usize,
+}
+
+
+impl Map {
+    fn new(grid: Grid<Rock>) -> Self {
+        let rows = grid.height();
+        let cols = grid.width();
+
+        Map { grid, rows, cols }
+    }
+
+
+    // One spin cycle tilts the platform north, then west, then south, then east
+    fn spin_cycle(&mut self) {
+        for dir in [Dir4::N, Dir4::W, Dir4::S, Dir4::E] {
+            self.tilt_all(dir);
+        }
+    }
+
+    // Tilts the whole platform, moving the rocks nearest that edge first so they don't block the ones behind them
+    fn tilt_all(&mut self, dir: Dir4) {
+        match dir {
+            Dir4::N => {
+                for i in 0..self.rows {
+                    let round_rocks = self.find_round_rocks_row(i);
+                    for rock in round_rocks {
+                        self.tilt(dir, rock);
+                    }
+                }
+            },
+
+            Dir4::W => {
+                for i in 0..self.cols {
+                    let round_rocks = self.find_round_rocks_col(i);
+                    for rock in round_rocks {
+                        self.tilt(dir, rock);
+                    }
+                }
+            },
+
+            Dir4::S => {
+                for i in (0..self.rows).rev() {
+                    let round_rocks = self.find_round_rocks_row(i);
+                    for rock in round_rocks {
+                        self.tilt(dir, rock);
+                    }
+                }
+            },
+
+            Dir4::E => {
+                for i in (0..self.cols).rev() {
+                    let round_rocks = self.find_round_rocks_col(i);
+                    for rock in round_rocks {
+                        self.tilt(dir, rock);
+                    }
+                }
+            }
+        }
+    }
+
+    fn tilt(&mut self, dir: Dir4, start_pos: (i32, i32)) {
+        let mut rock = start_pos;
+        while let Some(new) = self.shift_once(dir, rock) {
+            rock = new;
+        }
+    }
+
+    // Rolls the rock at pos one space in the given direction, if that space is on the platform and empty
+    fn shift_once(&mut self, dir: Dir4, pos: (i32, i32)) -> Option<(i32, i32)> {
+        let new_pos = dir.step_on(&self.grid, pos)?;
+        if self.grid.get(new_pos.0, new_pos.1) != Some(&Rock::Empty) {
+            return None;
+        }
+
+        let current_rock = self.grid.set(pos.0, pos.1, Rock::Empty)?;
+        self.grid.set(new_pos.0, new_pos.1, current_rock);
+        Some(new_pos)
+    }
+
+    fn find_round_rocks_row(&self, row: usize) -> Vec<(i32, i32)> {
+        let mut round = Vec::new();
+
+        for (col, rock) in self.grid.row(row).unwrap_or_default().iter().enumerate() {
+            if *rock == Rock::Round {
+                round.push((col as i32, row as i32));
+            }
+        }
+        round
+    }
+
+    fn find_round_rocks_col(&self, col: usize) -> Vec<(i32, i32)> {
+        let mut round = Vec::new();
+
+        for (row, rock) in self.grid.column(col).enumerate() {
+            if *rock == Rock::Round {
+                round.push((col as i32, row as i32));
+            }
+        }
+        round
+    }
+
+    fn count_round_rocks(&self, row: usize) -> usize {
+        let rocks = self.find_round_rocks_row(row);
+        rocks.len()
+    }
+
+    fn calculate_load(&self) -> usize {
+        (0..self.rows).map(|i| (self.rows - i) * self.count_round_rocks(i)).sum()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let map = Day14.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(north_load(&map), 136);
+        assert_eq!(load_after_spins(&map, 1000000000), 64);
+        Ok(())
+    }
+}
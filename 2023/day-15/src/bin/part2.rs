@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_15::Day15;
+
+fn main() -> ExitCode {
+    run_solution::<Day15>(2)
+}
@@ -0,0 +1,121 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Vec<u8>>;
+
+    // The steps are comma separated; the trailing newline isn't part of the last one
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.trim_end().split(',').map(|step| step.as_bytes().to_vec()).collect())
+    }
+
+    fn part1(&self, steps: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(sum_hashes(steps))
+    }
+
+    fn part2(&self, steps: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(focusing_power(&arrange_lenses(steps)?))
+    }
+}
+
+// Get the hash value of a given vector of u8's
+fn hash(step: &[u8]) -> usize {
+    step.iter().fold(0, |acc, &ch| (acc + ch as usize) * 17 % 256)
+}
+
+pub fn sum_hashes(steps: &[Vec<u8>]) -> usize {
+    steps.iter().map(|step| hash(step)).sum()
+}
+
+// Runs through the steps, putting lenses into and taking them out of the boxes
+pub fn arrange_lenses(steps: &[Vec<u8>]) -> Result<Vec<Vec<(String, u8)>>, AocError> {
+    // Initialize the vector of boxes
+    let mut boxes: Vec<Vec<(String, u8)>> = vec![vec![]; 256];
+
+    // Iterate through the steps in the input and get the values, keeping track of
+    // where each step starts on the line so errors can point at it
+    let mut col = 1;
+    for step in steps {
+        let values = get_values(step);
+
+        let label = String::from_utf8_lossy(&values[0]).into_owned();
+        let hash = hash(&values[0]);
+
+        // Checks if the second vector in the values vector has anything in it (i.e., if the
+        // delimiter is '=' and there's an associated lens)
+        if let Some(&v) = values.get(1).and_then(|lens| lens.last()) {
+
+            // If the lens number exists, convert it from its u8 ASCII value to an integer
+            if !v.is_ascii_digit() {
+                return Err(AocError::parse(1, col + step.len() - 1, format!("bad lens in step {}", label)));
+            }
+            let num = v - b'0';
+
+            // If the current label already exists in the box, get its index and update
+            // its lens number
+            if let Some(index) = boxes[hash].iter().position(|(s, _)| s == &label) {
+                boxes[hash][index].1 = num;
+
+            // If the current label doesn't exist, add it
+            } else {
+                boxes[hash].push((label, num));
+            }
+
+        // If the second value doesn't exist (i.e., the delimiter is '-' and there's no
+        // associated lens), check if the current label exists in the appropriate box
+        // and remove it if it does
+        } else if let Some(index) = boxes[hash].iter().position(|(s, _)| s == &label) {
+            boxes[hash].remove(index);
+        }
+
+        col += step.len() + 1;
+    }
+
+    Ok(boxes)
+}
+
+// Calculates the focusing power of the lenses currently in the boxes
+pub fn focusing_power(boxes: &[Vec<(String, u8)>]) -> usize {
+    let mut total = 0;
+
+    for (i, bx) in boxes.iter().enumerate() {
+        for (j, lens) in bx.iter().enumerate() {
+            total += (i + 1) * (j + 1) * lens.1 as usize;
+        }
+    }
+    total
+}
+
+// Gets the label (as u8 bytes) and the lens number, if it exists.
+// We'll end up with a vector of vectors of u8's. The label (as bytes)
+// is the first vector, and the lens value is the second. If the delimiter
+// is '-', the second vector will be empty. We'll use that later.
+fn get_values(step: &[u8]) -> Vec<Vec<u8>> {
+    let delimiter = if step.contains(&b'=') { b'=' } else { b'-' };
+
+    step
+        .split(|&ch| ch == delimiter)
+        .map(|splits| splits.to_vec())
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let steps = Day15.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(sum_hashes(&steps), 1320);
+        assert_eq!(focusing_power(&arrange_lenses(&steps)?), 145);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_16::Day16;
+
+fn main() -> ExitCode {
+    run_solution::<Day16>(1)
+}
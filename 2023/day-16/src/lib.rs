@@ -0,0 +1,205 @@
+use aoc_helpers::{ * };
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/*
+I probably way overengineered this, but it works, so I'll take it.
+*/
+
+/*
+The cool thing about overengineering part 1 is that part 2 was fairly simple to implement. Just had to add vecs holding the
+edge positions and iterate through them. Since we ignore any beam splitters that we've already visited, this is fairly
+efficient and runs in less than a second, even though it's technically a bruteforce solution.
+*/
+
+#[derive(Default)]
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input = Grid<Tile>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse_with(input, |ch| match ch {
+            '\\' => Ok(Tile::MirrorL),
+            '/' => Ok(Tile::MirrorR),
+            '|' => Ok(Tile::SplitterV),
+            '-' => Ok(Tile::SplitterH),
+            '.' => Ok(Tile::Empty),
+            _ => Err(()),
+        })
+    }
+
+    // The beam comes in from the left of the top left corner
+    fn part1(&self, grid: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(energize_from(grid, (0, 0), Dir4::E))
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(most_energized(grid))
+    }
+}
+
+// Fires a beam into the tile at `pos` heading in `dir` and counts the tiles it energizes
+pub fn energize_from(grid: &Grid<Tile>, pos: (i32, i32), dir: Dir4) -> usize {
+    let mut map = Map::new(grid.clone());
+
+    if let Some(start_tile) = map.get(pos) {
+        let (start_dir, _) = map.get_next_dir(dir, start_tile);
+        map.traverse(start_dir, pos);
+    }
+
+    map.energized.len()
+}
+
+// Tries a beam coming in from every tile around the edge, heading into the grid
+pub fn most_energized(grid: &Grid<Tile>) -> usize {
+    let rows = grid.height() as i32;
+    let cols = grid.width() as i32;
+
+    let mut top_edge: Vec<(i32, i32)> = Vec::new();
+    let mut bottom_edge: Vec<(i32, i32)> = Vec::new();
+    let mut left_edge: Vec<(i32, i32)> = Vec::new();
+    let mut right_edge: Vec<(i32, i32)> = Vec::new();
+
+    for row in 0..rows {
+        left_edge.push((0, row));
+        right_edge.push((cols - 1, row));
+    }
+
+    for col in 0..cols {
+        top_edge.push((col, 0));
+        bottom_edge.push((col, rows - 1));
+    }
+
+    let edges = [(&top_edge, Dir4::S), (&bottom_edge, Dir4::N), (&left_edge, Dir4::E), (&right_edge, Dir4::W)];
+
+    edges
+        .iter()
+        .flat_map(|(edge, dir)| edge.iter().map(move |pos| energize_from(grid, *pos, *dir)))
+        .max()
+        .unwrap_or(0)
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Tile {
+    MirrorR,
+    MirrorL,
+    SplitterV,
+    SplitterH,
+    Empty,
+}
+
+
+#[derive(Debug)]
+struct Map {
+    grid: Grid<Tile>,
+    energized: HashSet<(i32, i32)>,
+    beam_stack: Vec<(Dir4, (i32, i32))>,
+    splits: HashSet<(i32, i32)>,
+    mirrors: HashSet<((i32, i32), Dir4)>,
+}
+
+
+impl Map {
+    fn new(grid: Grid<Tile>) -> Self {
+        let energized: HashSet<(i32, i32)> = HashSet::new();
+        let beam_stack: Vec<(Dir4, (i32, i32))> = Vec::new();
+        let splits: HashSet<(i32, i32)> = HashSet::new();
+        let mirrors: HashSet<((i32, i32), Dir4)> = HashSet::new();
+
+        Map { grid, energized, beam_stack, splits, mirrors }
+    }
+
+    fn get(&self, pos: (i32, i32)) -> Option<Tile> {
+        self.grid.get(pos.0, pos.1).cloned()
+    }
+
+    // Moves the beam one tile on, unless that leaves the grid or a beam has already been through
+    // the next tile heading the same way, in which case there's nothing new down this path
+    fn step(&mut self, dir: Dir4, start_pos: (i32, i32)) -> Option<(Dir4, (i32, i32), bool)> {
+        let next_pos = dir.step_on(&self.grid, start_pos)?;
+        let tile = self.get(next_pos)?;
+
+        if !self.mirrors.insert((next_pos, dir)) {
+            return None;
+        }
+
+        let (next_dir, split) = self.get_next_dir(dir, tile);
+        Some((next_dir, next_pos, split))
+    }
+
+    // '/' turns a beam heading north or south to the right, and one heading east or west to the left;
+    // '\' does the reverse. A splitter hit side on sends one beam north (or east) and spawns the other.
+    fn get_next_dir(&self, dir: Dir4, tile: Tile) -> (Dir4, bool) {
+        let vertical = matches!(dir, Dir4::N | Dir4::S);
+
+        match tile {
+            Tile::MirrorR if vertical => (dir.turn_right(), false),
+            Tile::MirrorR => (dir.turn_left(), false),
+            Tile::MirrorL if vertical => (dir.turn_left(), false),
+            Tile::MirrorL => (dir.turn_right(), false),
+            Tile::SplitterH if vertical => (Dir4::E, true),
+            Tile::SplitterV if !vertical => (Dir4::N, true),
+            _ => (dir, false),
+        }
+    }
+
+    fn traverse(&mut self, dir: Dir4, pos: (i32, i32)) {
+        let mut current_dir = dir;
+        let mut current_pos = pos;
+
+        loop {
+
+            self.energize(current_pos);        
+            if let Some((next_dir, next_pos, split)) = self.step(current_dir, current_pos) {
+
+                current_dir = next_dir;
+                current_pos = next_pos;
+    
+                if split {
+                    self.spawn_beam(current_pos, current_dir.opposite());
+                }
+            } else {
+                break;
+            }
+        }
+        if !self.beam_stack.is_empty() {
+            self.next_beam();
+        }
+    }
+
+    fn energize(&mut self, pos: (i32, i32)) {
+        self.energized.insert(pos);
+    }
+
+    fn spawn_beam(&mut self, pos: (i32, i32), dir: Dir4) {
+        if !self.splits.contains(&pos) {
+            self.splits.insert(pos);
+            self.beam_stack.push((dir, pos));
+        }
+    }
+
+    fn next_beam(&mut self) {
+        let (dir, pos) = self.beam_stack.pop().unwrap();
+        self.traverse(dir, pos);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = Day16.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(energize_from(&grid, (0, 0), Dir4::E), 46);
+        assert_eq!(most_energized(&grid), 51);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_helpers::{ * };
+use std::process::ExitCode;
+use day_17::Day17;
+
+fn main() -> ExitCode {
+    run_solution::<Day17>(1)
+}
@@ -0,0 +1,103 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+/*
+I used a weird custom implementation of Dijkstra to make this work. The constraint of no more than 3 steps in the same direction was a pain,
+but I was able to make it work (sort of) by implementing the grid as a kind of 4D array. The (x, y) dimensions are 1 and 2, the direction used
+to get to the current node is 3, and the number of sequential straight-line movements into the current node is dimension 4. Using this, I was
+able to build a custom Dijkstra implementation to find the shortest path from the start to the end.
+
+I was honestly shocked that this worked. It took like 10 minutes to run, which told me I'd done something horribly wrong: it filled in a
+distance for every possible node up front, and its heap didn't actually hand back the cheapest node first. Now the neighbours are worked out
+as the search gets to them and the Dijkstra from the helpers does the searching, which gets the same answer in well under a second.
+*/
+
+#[derive(Default)]
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse_with(input, |ch| ch.to_digit(10).ok_or(()))
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<impl Display, AocError> {
+        Map { grid }.shortest_path().ok_or_else(|| AocError::parse(1, 1, "there's no way to the bottom right corner"))
+    }
+}
+
+
+// Where the crucible is, which way it moved to get there, how many moves in a row it's made in that direction (not
+// counting the first), and the heat lost entering this block
+#[derive(PartialEq, Eq, Debug, Hash, Clone, Copy, Ord, PartialOrd)]
+struct Node {
+    pos: (i32, i32),
+    direction: Option<Dir4>,
+    consecutive_dir: usize,
+    cost: u32
+}
+
+
+struct Map<'a> {
+    grid: &'a Grid<u32>,
+}
+
+impl Map<'_> {
+    // The crucible can't turn back on itself, or go more than three blocks in a straight line
+    fn next_node(&self, current: Node, dir: Dir4) -> Option<Node> {
+        if current.direction == Some(dir.opposite()) || (current.direction == Some(dir) && current.consecutive_dir > 1) {
+            return None;
+        }
+
+        let pos = dir.step_on(self.grid, current.pos)?;
+        let consecutive = if Some(dir) == current.direction {
+            current.consecutive_dir + 1
+        } else {
+            0
+        };
+
+        Some(Node { pos, direction: Some(dir), consecutive_dir: consecutive, cost: *self.grid.get(pos.0, pos.1)? })
+    }
+
+    fn get_neighbors(&self, current: Node) -> Vec<Node> {
+        Dir4::ALL.into_iter().filter_map(|dir| self.next_node(current, dir)).collect()
+    }
+
+    // The least heat that can be lost getting from the top left to the bottom right. Every node the search reaches
+    // in the bottom right corner counts, whichever way it came in.
+    fn shortest_path(&self) -> Option<u32> {
+        let end_pos = (self.grid.width() as i32 - 1, self.grid.height() as i32 - 1);
+        let start_node = Node {
+            pos: (0, 0),
+            direction: None,
+            consecutive_dir: 0,
+            cost: *self.grid.get(0, 0)?,
+        };
+
+        let path = dijkstra(
+            start_node,
+            |node| self.get_neighbors(*node).into_iter().map(|next| (next, next.cost)),
+            |node| node.pos == end_pos,
+        )?;
+
+        Some(path.cost)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let grid = Day17.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(Map { grid: &grid }.shortest_path(), Some(102));
+        Ok(())
+    }
+}
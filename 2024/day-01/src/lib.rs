@@ -0,0 +1,80 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+// Normally I'd try to make this a lot more polished than this, but I actually had a handle on the
+// problem within a few minutes of it dropping, so I was focused on speed rather than polish. I'll
+// clean this up later, but the important thing is that it works, okay??
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        get_vecs_from_input(input)
+    }
+
+    fn part1(&self, (left, right): &Self::Input) -> Result<impl Display, AocError> {
+        Ok(total_distance(left.clone(), right.clone()))
+    }
+
+    fn part2(&self, (left, right): &Self::Input) -> Result<impl Display, AocError> {
+        Ok(similarity_score(left, right))
+    }
+}
+
+pub fn total_distance(mut left: Vec<u32>, mut right: Vec<u32>) -> u32 {
+
+    // Sort the two vectors
+    // Probably unnecessary since this was plenty fast enough using stable sort, but optimizing a 
+    // bit using unstable sort since we don't care about the order of identical numbers
+    left.sort_unstable();
+    right.sort_unstable();
+
+    // Found a more efficient way of summing the differences between each vector entry than the
+    // way I was doing it with a for loop or whatever
+    left
+        .iter()
+        .zip(right.iter())
+        .map(|(l, r)| l.abs_diff(*r))
+        .sum()
+}
+
+pub fn similarity_score(left: &[u32], right: &[u32]) -> u32 {
+
+    // Running total
+    let mut total = 0;
+
+    // For each entry in the left vector, count the number of times that value appears in the right 
+    // vector and then add it to the running total
+
+    // There's definitely a better way to do this, but that's gonna take some more Rust research,
+    // and honestly I don't care at this point. It works, shut up.
+    for i in 0..left.len() {
+        let current_count = right
+            .iter()
+            .filter(|&&x| x == left[i])
+            .count();
+
+        total += left[i] * current_count as u32;
+    }
+
+    total
+}
+
+// Converts our original vector of strings to a pair of vectors of ints (left and right)
+fn get_vecs_from_input(input: &str) -> Result<(Vec<u32>, Vec<u32>), AocError> {
+
+    // Used some Rust magic to make this more efficient than my previous method of iterating
+    // across each line, splitting by whitespace, and pushing to a couple of vectors.
+    let pairs = parse_lines(input, |line| match ints::<u32>(line)?[..] {
+        [left, right] => Ok((left, right)),
+        _ => Err(AocError::parse(1, 1, format!("expected two location IDs, got {:?}", line))),
+    })?;
+
+    Ok(pairs.into_iter().unzip())
+}
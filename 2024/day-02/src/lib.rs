@@ -0,0 +1,119 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+// Rust is wild, ya know? But I'm slowly starting to get the hang of this iter() nonsense, and it's
+// actually kinda cool.
+
+#[derive(Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, ints)
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_safe_reports(reports))
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_dampened_reports(reports))
+    }
+}
+
+// Count the number of reports in the report vector that meet the criteria of being "safe"
+pub fn count_safe_reports(reports: &[Vec<i8>]) -> u32 {
+
+    // Iterate over the elements in the outer vector
+    reports
+        .iter()
+
+        // Look at each slice of 2 entries in the current inner vec, checking
+        .map(|v| {
+
+            // Get the direction (increasing, decreasing, or static) of the first pair in the vector for comparison
+            let direction = (v[0] - v[1]).signum();
+
+            // Look at each 2-entry slice in the vector to ensure that the absolute value of the difference is between
+            // 1 and 3, and the direction doesn't change
+            v.windows(2).all(|w| {
+                let diff = w[0] - w[1];
+                (1..4).contains(&diff.abs()) && diff.signum() == direction
+            })
+        })
+
+        // Count the reports where all those criteria are true and return the total
+        .filter(|x| *x)
+        .count() as u32
+    }
+
+// Well this is alarmingly inefficient. I'm researching ways to make it more 
+// efficient, but it works for now, so don't @ me.
+
+// Count the number of reports that are safe once the Problem Dampener is allowed to drop a single level
+pub fn count_dampened_reports(reports: &[Vec<i8>]) -> u32 {
+    let mut result: u32 = 0;
+
+    for report in reports {
+
+        // Here's the scary part. Check the given report without removing anything. If it's
+        // safe, we're done, increment result and move on. Otherwise, iterate through all
+        // entries in the report, run check_report() again removing the current entry. If 
+        // it's safe, we're done, increment result and move on to the next report, otherwise 
+        // keep going. Yeah, I know. 
+        if !check_report(&mut report.clone(), None) {
+            for i in 0..report.len() {
+                if check_report(&mut report.clone(), Some(i)) {
+                    result += 1;
+                    break;
+                }
+            }
+        } else {
+            result += 1;
+        }        
+    }
+
+    result
+}
+
+// Checks a single report with, removing 0 or 1 entries from the vector
+// beforehand based on the skip parameter. 
+fn check_report(report: &mut Vec<i8>, skip: Option<usize>) -> bool {
+    if let Some(i) = skip {
+        report.remove(i);
+    }
+
+    let dir = (report[1] - report[0]).signum();
+    if dir == 0 {
+        return false;
+    }
+
+    report
+        .windows(2)
+        .all(|w| {
+            let diff = w[1] - w[0];
+            (1..4).contains(&diff.abs()) && diff.signum() == dir
+    })
+}
+
+// Iterate over a vector of strings, split each string by whitespace and parse each entry
+// into a signed 8-bit integer, and slap them into a vector of vectors of ints.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() -> Result<(), Box<dyn std::error::Error>> {
+        let reports = Day02.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(count_safe_reports(&reports), 2);
+        assert_eq!(count_dampened_reports(&reports), 4);
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_helpers::{ * };
+use regex::Regex;
+use lazy_static::lazy_static;
+use std::fmt::Display;
+
+// The hardest part about this one was learning how regex works. And how Rust handles it.
+
+#[derive(Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(&self, lines: &Self::Input) -> Result<impl Display, AocError> {
+        find_matches(lines)
+    }
+
+    fn part2(&self, lines: &Self::Input) -> Result<impl Display, AocError> {
+        find_enabled_matches(lines)
+    }
+}
+
+pub fn find_matches(input: &[String]) -> Result<u32, AocError> {
+
+    lazy_static!(
+        static ref RE: Regex = Regex::new(r"mul\([0-9]{1,3},[0-9]{1,3}\)").unwrap();
+    );
+    
+    let mut total: u32 = 0;
+
+    // Iterate through the strings
+    for line in input {
+
+        // Run the regex and store the matches in a vector, then multiply out each "mul(xx,yy)"
+        let ans: Vec<_> = RE.find_iter(line).collect();
+
+        for cap in ans {
+            total += parse_and_multiply(cap.as_str())?;
+        }
+    }
+    Ok(total)
+}
+
+// This one was pretty straightforward once I actually figured out how Rust does regex. 
+// We iterate over the regex matches in order from the beginning of the string to the end, 
+// using a bool to determine whether multiplication is enabled. If it's not, ignore any
+// mul() matches.
+pub fn find_enabled_matches(input: &[String]) -> Result<u32, AocError> {
+
+    // The regex adds "do()" and "don't()" as match strings
+    lazy_static!(
+        static ref RE: Regex = Regex::new(r"(mul\([0-9]{1,3},[0-9]{1,3})\)|(do\(\))|(don\'t\(\))").unwrap();
+    );
+    
+    let mut total: u32 = 0;
+    let mut enabled: bool = true;
+
+    for line in input {
+        let ans: Vec<_> = RE.find_iter(line).collect();
+
+        for cap in ans {
+            match cap.as_str() {
+                "do()" => enabled = true,
+                "don't()" => enabled = false,
+                
+                _ => {
+                    if enabled {
+                        total += parse_and_multiply(cap.as_str())?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(total)
+}
+
+// Get the match ("mul(xx,yy)"), get the substring to narrow it down to just the digits and comma,
+// then split it, parse the results into u32s, and multiply them.
+fn parse_and_multiply(s: &str) -> Result<u32, AocError> {
+    let substr = &s[4..s.len() - 1];
+    let mut split = substr.split(',');
+
+    let mut next = |which: &str| -> Result<u32, AocError> {
+        split
+            .next()
+            .and_then(|n| n.trim().parse::<u32>().ok())
+            .ok_or_else(|| AocError::parse(1, 1, format!("Bad {} number in {}", which, s)))
+    };
+
+    Ok(next("first")? * next("second")?)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let first = Day03.parse(&read_from_file_as_string("example1.txt")?)?;
+        let second = Day03.parse(&read_from_file_as_string("example2.txt")?)?;
+
+        assert_eq!(find_matches(&first)?, 161);
+        assert_eq!(find_enabled_matches(&second)?, 48);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+use aoc_helpers::{ * };
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day04;
+
+pub struct WordSearch {
+    pub grid: HashMap<(i32, i32), char>,
+    pub rows: usize,
+    pub cols: usize,
+    pub candidates: Vec<(i32, i32)>,
+}
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Input = WordSearch;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let rows = lines.len();
+        let cols = lines[0].len();
+
+        let (grid, candidates) = input_to_hashmap(&lines);
+        Ok(WordSearch { grid, rows, cols, candidates })
+    }
+
+    fn part1(&self, search: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(word_search(&search.grid, search.rows, search.cols))
+    }
+
+    fn part2(&self, search: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(x_search(&search.grid, &search.candidates))
+    }
+}
+
+pub fn word_search(grid: &HashMap<(i32, i32), char>, rows: usize, cols: usize) -> u32 {
+    let mut total: u32 = 0;
+    
+    // Iterate across the top row, checking each vertical row and as many diagonal lines as
+    // we can reach starting from this row
+    for x in 0..cols {
+        let pos = (x as i32, 0);
+        total += check_dir(grid, pos, Dir8::S);
+        total += check_dir(grid, pos, Dir8::SW);
+        total += check_dir(grid, pos, Dir8::SE);
+    }
+
+    // Iterate across the left column, checking each horizontal column and the diagonal
+    // lines that we couldn't reach from the top row
+    for y in 0..rows {
+        let pos = (0, y as i32);
+        total += check_dir(grid, pos, Dir8::E);
+
+        // We've already counted the SE diagonal row starting from (0, 0), so skip that
+        if y != 0 {
+            total += check_dir(grid, pos, Dir8::SE);
+            
+            // There are some SW diagonal lines that we need to get from the bottom row,
+            // excluding the row where y == 0, which we already checked
+            let pos = ((cols - 1) as i32, y as i32);
+            total += check_dir(grid, pos, Dir8::SW);
+        }
+    }
+
+    total
+}
+
+// Get the chars starting from start_pos in the direction dir, put them into a vector, then
+// count the number of times "XMAS" or "SAMX" (XMAS backwards) appear in the vector. This 
+// lets us check for both forward and backward words at the same time.
+fn check_dir(grid: &HashMap<(i32, i32), char>, start_pos: (i32, i32), dir: Dir8) -> u32 {
+    let mut buf: Vec<char> = Vec::new();
+
+    let mut pos = start_pos;
+
+    // Iterate across the grid in the given direction until getting the value at pos returns
+    // a None value, which means we've reached the edge of the grid. Push each character we
+    // reach into a buffer to be checked when we're done.
+    while let Some(ch) = grid.get(&pos) { 
+        buf.push(*ch);
+        pos = dir.step(pos);
+    }
+
+    // Once we have a fully populated buffer, count the number of times these words appear
+    let words = vec!["XMAS", "SAMX"];
+    count_words_in_buffer(buf, words)
+}
+
+// Takes a vector of chars and a vector of words to check for, and returns the number of 
+// times any of those words appear in the buffer.
+fn count_words_in_buffer(buf: Vec<char>, words: Vec<&str>) -> u32 {
+    let mut total: usize = 0;
+
+    for word in words {
+        let word_chars: Vec<char> = word.chars().collect();
+
+        let count = buf
+            .windows(word_chars.len())
+            .filter(|w| *w == word_chars)
+            .count();
+        total += count;
+    }
+    total as u32
+}
+
+// My original idea was to iterate over every valid position in the grid checking if it
+// matched the pattern. But I figured we could save some time by doing a little bit of
+// preprocessing. A valid pattern has to have an 'A' in the center, so when we generate
+// the grid, we check for all 'A' characters that aren't on the outer edge of the grid
+// and add their positions to a list of candidates. Then we can just iterate over the
+// list of candidates instead of going through all characters. Seems to work all right.
+
+// Iterate through each coordinate in the candidate list, checking if it matches the pattern,
+// then return the total number of matches
+pub fn x_search(grid: &HashMap<(i32, i32), char>, candidates: &[(i32, i32)]) -> usize {
+      candidates
+        .iter()
+        .filter(|p| {
+            check_for_x(grid, **p)
+        })
+        .count()
+}
+
+// Check a specific X from the candidate list (list of all 'A's in the grid that
+// aren't on the outer edge of the grid) and check if it matches the pattern
+fn check_for_x(grid: &HashMap<(i32, i32), char>, pos: (i32, i32)) -> bool {
+    let (x, y) = pos;
+
+    // Relative (x, y) positions in the left and right diagonals. We don't care
+    // about the center since we already know it's an 'A'.
+    let l_diag: [(i32, i32); 2] = [(x - 1, y - 1), (x + 1, y + 1)];
+    let r_diag: [(i32, i32); 2] = [(x + 1, y - 1), (x - 1, y + 1)];
+
+    // In order to match the pattern, both diagonals have to be "MAS" or "SAM".
+    // We already know the center is 'A', so we're checking for "MS" or "SM"
+    let check_diagonal = |diag: &[(i32, i32); 2]| -> bool {
+        let chars: Vec<char> = diag.iter()
+            .filter_map(|&p| grid.get(&p))
+            .cloned()
+            .collect();
+        chars == vec!['S', 'M'] || chars == vec!['M', 'S']
+    };
+
+    check_diagonal(&l_diag) && check_diagonal(&r_diag)
+}
+
+// Convert the input from a vector of strings into a hashmap with the (x, y)
+// coordinates as the key, and the character at those coords as the value, then
+// generate a list of all coordinates that could be the center of an X-mas to
+// speed up processing in part 2.
+fn input_to_hashmap(input: &[&str]) -> (HashMap<(i32, i32), char>, Vec<(i32, i32)>) {
+    let max_x = input.len() - 1;
+    let max_y = input[0].len() - 1; 
+
+    let mut grid: HashMap<(i32, i32), char> = HashMap::new();
+    let mut candidates: Vec<(i32, i32)> = Vec::new();
+
+    input
+        .iter()
+        .enumerate()
+        .for_each(|(y, s)| {
+            s.chars()
+                .enumerate()
+                .for_each(|(x, ch)| {
+                    grid.insert((x as i32, y as i32), ch);
+
+                    // If the current char is 'A' and it's not on the exterior ring
+                    // of the grid, the current position is a candidate for an X-mas,
+                    // so add it to the list.
+                    if ch == 'A' && x != 0 && x != max_x && y != 0 && y != max_y {
+                        candidates.push((x as i32, y as i32));
+                    }
+                })
+        });
+        (grid, candidates)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let search = Day04.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(word_search(&search.grid, search.rows, search.cols), 18);
+        assert_eq!(x_search(&search.grid, &search.candidates), 9);
+        Ok(())
+    }
+}
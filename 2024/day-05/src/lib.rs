@@ -0,0 +1,164 @@
+use aoc_helpers::{ * };
+use std::collections::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day05;
+
+// Each rule is an (X, Y) pair saying page X has to come before page Y
+pub type Rules = Vec<(u32, u32)>;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Input = (Rules, Vec<Vec<u32>>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        process_input(input)
+    }
+
+    fn part1(&self, (rules, pages): &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_correct_pages(rules, pages))
+    }
+
+    fn part2(&self, (rules, pages): &Self::Input) -> Result<impl Display, AocError> {
+        Ok(get_sorted_result(&rules_to_map(rules), &mut pages.clone()))
+    }
+}
+
+// I'm slowly getting the hang of iterators in Rust. Pretty impressed tbh. 
+pub fn count_correct_pages(rules: &[(u32, u32)], pages: &[Vec<u32>]) -> u32 {
+
+    // Iterate over each set of pages in the list, then iterate over each rule in
+    // the rules list. If both entries in the current rule exist in the current 
+    // set of pages, check their order within the current set, and if it's correctly
+    // sorted, add the middle element from that vector to an accumulator that Rust
+    // so conveniently provides for us. Then return the accumulator.
+    pages
+        .iter()
+        .filter(|page| {
+            rules
+                .iter()
+                .filter(|(x, y)| page.contains(x) && page.contains(y))
+                .all(|(x, y)| check_order(page, *x, *y))
+        })
+        .map(|page| {
+            let middle = page.len() / 2;
+            page[middle]
+        })
+        .sum()
+}
+
+// Checks if two entries in a page list are correctly sorted. This should get passed the
+// current page list, and an x and y corresponding to a specific rule from the rule list,
+// where x is the first number and y is the second, indicating that y should come after
+// x in the page list.
+fn check_order(page: &[u32], x: u32, y: u32) -> bool {
+    let x_pos = page.iter().position(|n| *n == x).unwrap();
+    let y_pos = page.iter().position(|n| *n == y).unwrap();
+
+    y_pos > x_pos
+}
+
+// I'm just learning all the things. Like how Rust handles custom sorting orders, and efficient ways
+// to preprocess inputs to speed up execution. The custom sorting rules have a list of pairs of
+// integers, (X|Y), where if both X and Y exist in a list of pages, X must appear at some point
+// before Y. To make this simpler, I converted that into a hashmap with X as a key, and as a 
+// value, a hashset containing all Y values that correspond with the given X in any of our rules.
+
+// This hashset gets passed to a function that defines the custom sorting rules, such that if
+// we're evaluating a specific pair of values (A, B) in a list of pages, if A exists as an X in
+// the rules map and B is one of its corresponding Y values, we consider A to be less than B for
+// purposes of the sort, and vice versa. If only A or B exist as an X or Y, we consider the two
+// to be equal.
+
+// Then we just run through each set of pages, filter down to the ones that aren't initially
+// sorted, sort them, and sum their middle values.
+
+pub fn get_sorted_result(rules: &HashMap<u32, HashSet<u32>>, pages: &mut [Vec<u32>]) -> u32 {
+
+    // Iterate over each set of pages, getting only the ones that are not initially sorting using
+    // our custom ordering rules
+    pages.iter_mut()
+        .filter(|page| !page.is_sorted_by(|a, b| {
+            let ordering = custom_sort(a, b, rules);
+            ordering == Ordering::Less
+        }))
+
+        // For each of the filtered page lists, sort it using our custom rules, then add the middle
+        // element to the handy built-in accumulator that Rust gives us. Then return it.
+        .map(|page| {
+            page.sort_by(|a, b| custom_sort(a, b, rules));
+
+            let middle = page.len() / 2;
+            page[middle]
+        })
+        .sum()
+}
+
+// Set up the custom sorting rules, evaluating a specific (a, b) pair from a list of
+// pages to determine whether we consider a to be <, >, or = b.
+fn custom_sort(a: &u32, b: &u32, rules: &HashMap<u32, HashSet<u32>>) -> Ordering {
+    
+    // If (a, b) corresponds to an (X, Y) pair in a rule, a < b
+    if let Some(set) = rules.get(a) {
+        if set.contains(b) {
+            return Ordering::Less;
+        }
+    }
+
+    // If (b, a) corresponds to an (X, Y) pair in a rule, a > b
+    if let Some(set) = rules.get(b) {
+        if set.contains(a) {
+            return Ordering::Greater;
+        }
+    }
+
+    // Otherwise, a == b
+    Ordering::Equal
+}
+
+// Process puzzle input into the appropriate rules and pages vectors. The rules and the page lists are
+// separated by a blank line, and every line in both is just numbers with some punctuation in between.
+fn process_input(input: &str) -> Result<(Rules, Vec<Vec<u32>>), AocError> {
+    let sections = blocks(input);
+    let (rules, pages) = match sections[..] {
+        [rules, pages] => (rules, pages),
+        _ => return Err(AocError::parse(1, 1, "expected rules and pages separated by a blank line")),
+    };
+
+    let r = Pattern::new("{}|{}").parse_lines(rules)?;
+    let p = parse_lines(pages, ints)?;
+
+    Ok((r, p))
+}
+
+// Preprocess the rules into a hashmap of X values to the set of every Y that has to come after them
+fn rules_to_map(rules: &[(u32, u32)]) -> HashMap<u32, HashSet<u32>> {
+    let mut rules_map: HashMap<u32, HashSet<u32>> = HashMap::new();
+
+    rules
+        .iter()
+        .for_each(|&(x, y)| {
+            rules_map.entry(x).or_default().insert(y);
+        });
+
+    rules_map
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let (rules, mut pages) = Day05.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(count_correct_pages(&rules, &pages), 143);
+        assert_eq!(get_sorted_result(&rules_to_map(&rules), &mut pages), 123);
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use aoc_helpers::{ * };
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Input = (HashMap<(i32, i32), char>, (i32, i32));
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input_to_grid(input))
+    }
+
+    fn part1(&self, (grid, start_pos): &Self::Input) -> Result<impl Display, AocError> {
+        count_visited(grid.clone(), *start_pos).ok_or_else(|| AocError::Solution {
+            year: Self::YEAR,
+            day: Self::DAY,
+            part: 1,
+            message: "the guard never leaves the map".to_string(),
+        })
+    }
+
+    fn part2(&self, (grid, start_pos): &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_cycles(grid.clone(), *start_pos))
+    }
+}
+
+// This one was fun. I decided to make a Guard struct with methods for moving, turning,
+// parsing the next spot in the grid, etc. We create an instance of Guard and set her off
+// patrolling, and next_state() handles everything else. Every position she passes through
+// goes into a hashset that ends up containing every unique position visited.
+
+// Part 1 just lets the guard patrol once and counts the positions she covers, or None if
+// she never leaves
+pub fn count_visited(grid: HashMap<(i32, i32), char>, start_pos: (i32, i32)) -> Option<usize> {
+    let guard = Guard::new(*grid.get(&start_pos).unwrap(), start_pos, grid);
+
+    guard.patrol().map(|visited| visited.len())
+}
+
+// This one took some thinking. I had to track down several edge cases that were tripping me up
+// and just generally ruining my life, but we're here now.
+
+// Similar to part 1, we have a Guard struct to model the guard's movements. We let the guard
+// patrol once without any extra obstacles to get her normal path to narrow down the pool of
+// positions where we can add an obstacle to change the guard's path. We then iterate across
+// all positions in the path, adding a single obstacle at each and letting the guard patrol
+// to check for a cycle. Then we just count the number of cycles.
+
+#[derive(Debug)]
+pub struct Guard {
+    start_pos: (i32, i32),
+    start_dir: Dir4,
+    grid: HashMap<(i32, i32), char>,
+    added_obstacle: (i32, i32),
+}
+
+impl Guard {
+    pub fn new(ch: char, start_pos: (i32, i32), grid: HashMap<(i32, i32), char>) -> Self {
+
+        // The initial guard character determines the initial direction
+        let start_dir = Dir4::try_from(ch).expect("Invalid direction");
+
+        let added_obstacle = (-1, -1);
+
+        Guard { start_pos, start_dir, grid, added_obstacle }
+    }
+
+    // Every distinct position the guard covers before she leaves the grid, whichever way
+    // she was facing, or None if her patrol goes round in circles instead
+    pub fn patrol(&self) -> Option<HashSet<(i32, i32)>> {
+        if self.loops() {
+            return None;
+        }
+
+        let states = std::iter::successors(Some((self.start_pos, self.start_dir)), |&(pos, dir)| self.next_state(pos, dir));
+        Some(states.map(|(pos, _)| pos).collect())
+    }
+
+    // Whether the guard's patrol goes round in circles forever. Her state is just her position and
+    // direction, so the shared cycle finder can check this without keeping a visited set at all.
+    fn loops(&self) -> bool {
+        ends_in_cycle((self.start_pos, self.start_dir), |&(pos, dir)| self.next_state(pos, dir))
+    }
+
+    // Where the guard is, and which way she's facing, one step after (pos, dir), or None once she's
+    // left the grid. She turns right as many times as she needs to before stepping.
+    fn next_state(&self, pos: (i32, i32), mut dir: Dir4) -> Option<((i32, i32), Dir4)> {
+        for _ in 0..4 {
+            let next = dir.step(pos);
+
+            match self.grid.get(&next) {
+                Some('#') => dir = dir.turn_right(),
+                Some(_) => return Some((next, dir)),
+                None => return None,
+            }
+        }
+
+        // Boxed in on all four sides, so she just spins on the spot
+        Some((pos, dir))
+    }
+
+    // Restore the grid to its original state (if applicable) and add an obstacle
+    // at the indicated position.
+    fn add_obstacle(&mut self, pos: (i32, i32)) {
+
+        if self.added_obstacle != (-1, -1) {
+            self.grid.insert(self.added_obstacle, '.');
+        }
+
+        // Add an obstacle to the listed position, then store that position so we
+        // can restore it next time this is called.
+        self.grid.insert(pos, '#');
+        self.added_obstacle = pos;
+    }
+
+}
+
+pub fn count_cycles(grid: HashMap<(i32, i32), char>, start_pos: (i32, i32)) -> usize {
+    
+    // Instantiate the guard and let her patrol once to get her path, in order to populate
+    // a list of positions where we could add an obstacle to change the path
+    let mut guard = Guard::new(*grid.clone().get(&start_pos).unwrap(), start_pos, grid);
+
+    let candidates: HashSet<(i32, i32)> = guard.patrol()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|&pos| pos != start_pos)
+        .collect();
+    
+    candidates.iter()
+        .filter(|coords| {
+            let pos = (coords.0, coords.1);
+            guard.add_obstacle(pos);
+            guard.loops()
+        })
+        .count()
+}
+
+// Take the input vector and return a hashmap representing the map grid, and the guard's
+// initial position as identified by the carat char in the input
+fn input_to_grid(input: &str) -> (HashMap<(i32, i32), char>, (i32, i32)) {
+    let mut grid: HashMap<(i32, i32), char> = HashMap::new();
+    let mut start_pos: (i32, i32) = (0, 0);
+
+    input
+        .lines()
+        .enumerate()
+        .for_each(|(y, s)| {
+            s.chars()
+                .enumerate()
+                .for_each(|(x, ch)| {
+
+                    // Save the current index and corresponding character to the grid
+                    grid.insert((x as i32, y as i32), ch);
+
+                    // If the current index contains the guard's starting position, save
+                    // that as start_pos
+                    if ['^', '>', '<', 'v'].contains(&ch) {
+                        start_pos = (x as i32, y as i32);
+                    }
+                })
+            });
+    (grid, start_pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let (grid, start_pos) = Day06.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(count_visited(grid.clone(), start_pos), Some(41));
+        assert_eq!(count_cycles(grid, start_pos), 6);
+        Ok(())
+    }
+}
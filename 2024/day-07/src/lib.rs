@@ -0,0 +1,103 @@
+use aoc_helpers::{ * };
+use itertools::Itertools;
+use std::fmt::Display;
+
+// This one took some research. Started with a brute force solution, iterating across all possible
+// permutations of '*' and '+' for each pair of numbers. I ended up optimizing a bit by pruning any
+// branches that exceed the result. Still seems like this would be pretty inefficient, but it runs
+// essentially instantly on the puzzle input, so I'll take it.
+
+// Part 2 adds some time complexity by adding an additional operator, but when compiled with --release
+// this still runs in a few seconds, so I'll take it.
+
+#[derive(Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, parse_equation)
+    }
+
+    fn part1(&self, equations: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(calibrate(equations, &["+", "*"]))
+    }
+
+    fn part2(&self, equations: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(calibrate(equations, &["+", "*", "||"]))
+    }
+}
+
+// Iterate over each line in the input, adding the target value to an accumulator
+// if the line can be evaluated to the target with the given operators
+pub fn calibrate(equations: &[(u64, Vec<u64>)], operators: &[&str]) -> u64 {
+    equations
+        .iter()
+        .fold(0, |acc, (target, nums)| {
+            if let Some(res) = evaluate(*target, nums, operators) {
+                acc + res
+            } else {
+                acc
+            }
+        })
+}
+
+fn evaluate(target: u64, nums: &[u64], operators: &[&str]) -> Option<u64> {
+    let permutations = std::iter::repeat_n(operators, nums.len() - 1)
+        .multi_cartesian_product();
+
+    for perm in permutations {
+        let mut result = nums[0];
+
+        for (op, &num) in perm.iter().zip(&nums[1..]) {
+            match **op {
+                
+                // "+" and "*" are self-explanatory
+                "+" => result += num,
+                "*" => result *= num,
+
+                // "||" concatenates the result to this point with the next number in the list 
+                "||" => {
+                    result = format!("{}{}", result, num).parse::<u64>().unwrap();
+                }
+
+                _ => unreachable!(),
+            }
+
+            if result > target {
+                break;
+            }
+        }
+
+        if result == target {
+            return Some(target);
+        }
+    }
+    None
+
+}
+
+// "190: 10 19" is the test value, then the numbers that have to make it
+fn parse_equation(line: &str) -> Result<(u64, Vec<u64>), AocError> {
+    let (left, right): (u64, String) = Pattern::new("{}:{}").parse(line)?;
+    Ok((left, ints(&right)?))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let equations = Day07.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(calibrate(&equations, &["+", "*"]), 3749);
+        assert_eq!(calibrate(&equations, &["+", "*", "||"]), 11387);
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+use aoc_helpers::{ * };
+use std::collections::{ HashMap, HashSet };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day08;
+
+pub struct Antennas {
+    pub grid: HashMap<(i32, i32), char>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Input = Antennas;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let rows = input.lines().count();
+        let cols = input.lines().next().map_or(0, |line| line.len());
+
+        Ok(Antennas { grid: input_to_grid(input), rows, cols })
+    }
+
+    fn part1(&self, antennas: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(find_antinodes(&antennas.grid, antennas.rows, antennas.cols))
+    }
+
+    fn part2(&self, antennas: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(find_harmonic_antinodes(&antennas.grid, antennas.rows, antennas.cols))
+    }
+}
+
+pub fn find_antinodes(grid: &HashMap<(i32, i32), char>, rows: usize, cols: usize) -> usize {
+    let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
+
+    // Generate a list of all possible slopes for lines in the given grid
+    let slopes = get_slopes(cols as i32, rows as i32);
+
+    // Iterate across all valid spots in the grid, tracing a line from each spot with each possible
+    // slope. Is there a way to optimize this and pare it down? Probably. But I'm honestly just 
+    // shocked that I got this far. I can always look into optimizing later.
+    for y in 0..rows {
+        for x in 0..cols {
+            let pos = (x as i32, y as i32);
+            for slope in slopes.clone() {
+
+                // Trace a line from the given start position with the given slope, generating a
+                // list of duplicate characters with their indices within the line
+                let dup = trace_line(grid, pos, slope);
+                if dup.is_empty() {
+                    continue;
+                }
+
+                // For each set of offsets in the dup hashmap, perform some checks to see if valid
+                // antinodes exist in the line we've generated
+                for (_, offsets) in dup {
+
+                    // Calculate the distance across the line between the duplicate chars
+                    let delta = offsets[0].abs_diff(offsets[1]);
+
+                    // In order to be a valid antinode, one of the towers has to be twice as far
+                    // from the given position than the other. From the puzzle input it seems like
+                    // we can assume that every set of towers on a line will have 2 valid antinodes,
+                    // but I'm still doing a bit of validation to ensure that both possible spots
+                    // are within the grid by checking whether getting the char from the grid 
+                    // hashmap doesn't return None, which would indicate that we're outside the grid.
+                    let index = offsets[0] as i32 - delta as i32;
+                    let new_pos = get_position(pos, slope, index);
+
+                    if grid.get(&new_pos).is_some() {
+                        antinodes.insert(new_pos);
+                    }
+
+                    let index = offsets[1] as i32 + delta as i32;
+                    let new_pos = get_position(pos, slope, index);
+
+                    if grid.get(&new_pos).is_some() {
+                        antinodes.insert(new_pos);
+                    }
+                }
+            }
+        }
+    }
+    antinodes.len()
+}
+
+// Return the (x, y) coordinates of a spot based on the slope of the line it's on, the start position,
+// and the index along the line
+fn get_position(pos: (i32, i32), slope: (i32, i32), index: i32) -> (i32, i32) {
+    (pos.0 + (slope.0 * index), pos.1 + (slope.1 * index))
+}
+
+// Part 2 was honestly a bit easier than part 1, once I got everything working with part 1.
+// With part 1, we had to do a bit of extra math to find the specific locations of the two
+// antinodes along a line. But with part 2, we just get every position along any line that
+// contains a duplicate. 
+
+// For simplicity, I'm checking every possible slope from every possible start position. 
+// There's definitely a way to optimize this and remove duplicate checks, and I'll probably
+// look into that later, but we're here now.
+
+pub fn find_harmonic_antinodes(grid: &HashMap<(i32, i32), char>, rows: usize, cols: usize) -> usize {
+    let mut antinodes: HashSet<(i32, i32)> = HashSet::new();
+
+    // Generate a list of all possible slopes for lines in the given grid
+    let slopes = get_slopes(cols as i32, rows as i32);
+
+    // Iterate across all valid spots in the grid, tracing a line from each spot with each possible
+    // slope. Is there a way to optimize this and pare it down? Probably. But I'm honestly just 
+    // shocked that I got this far. I can always look into optimizing later.
+    for y in 0..rows {
+        for x in 0..cols {
+            let pos = (x as i32, y as i32);
+            for slope in slopes.clone() {
+
+                // Trace a line from the given start position with the given slope, generating a
+                // list of duplicate characters with their indices within the line
+                let dup = trace_line(grid, pos, slope);
+
+                // If dup is empty, we don't have to worry about this line
+                if dup.is_empty() {
+                    continue;
+                }
+
+                // The only change needed for part 2 was to just get every position in a line,
+                // where duplicates exist, rather than doing a bunch of checks to find the two 
+                // specific antinodes on the line. I'm doing what may be a redundant check to 
+                // make sure that we're not iterating beyond the bounds of the grid.
+                let mut pos_ptr = pos;
+                while grid.get(&pos_ptr).is_some() {
+                    antinodes.insert(pos_ptr);
+                    slope_step(&mut pos_ptr, slope);
+                }
+            }
+        }
+    }
+    antinodes.len()
+}
+
+// Trace a single line, putting the chars along that line into a buffer, and then returning a hashmap of
+// any duplicate values in the line and their respective indices.
+fn trace_line(grid: &HashMap<(i32, i32), char>, start_pos: (i32, i32), slope: (i32, i32)) -> HashMap<char, Vec<usize>> {
+    let mut pos = start_pos;
+    let buf = fill_buffer(grid, &mut pos, slope);
+    
+    find_duplicates(buf)
+}
+
+// For a given start position and slope, fill a buffer with the characters in the resulting line
+fn fill_buffer(grid: &HashMap<(i32, i32), char>, pos: &mut (i32, i32), slope: (i32, i32)) -> Vec<char> {
+    let mut buf: Vec<char> = Vec::new();
+
+    while let Some(ch) = grid.get(pos) {
+        buf.push(*ch);
+        slope_step(pos, slope);
+    }
+
+    buf
+}
+
+// Takes a buffer of chars representing a single line, and return a hashmap of any duplicate chars and
+// their respective indices along the line
+fn find_duplicates(buf: Vec<char>) -> HashMap<char, Vec<usize>> {
+    let mut indices: HashMap<char, Vec<usize>> = HashMap::new();
+
+    for (i, &ch) in buf.iter().enumerate() {
+        if ch != '.' {
+            indices.entry(ch).or_default().push(i);
+        }
+    }
+
+    indices.into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .collect()
+}
+
+// Increment a position pointer along a line with a given slope
+fn slope_step(pos: &mut (i32, i32), slope: (i32, i32)) {
+    pos.0 += slope.0;
+    pos.1 += slope.1;
+}
+
+// Populate a hashmap with all possible slope values for a grid of a given size
+fn get_slopes(max_x: i32, max_y: i32) -> HashSet<(i32, i32)> {
+    let mut slopes: HashSet<(i32, i32)> = HashSet::new();
+
+    for dy in -max_y..max_y {
+        for dx in -max_x..max_x {
+            if dy == 0 && dx == 0 {
+                continue;
+            }
+            let divisor = gcd(dy, dx);
+            slopes.insert((dy / divisor, dx / divisor));
+        }
+    }
+
+    slopes
+}
+
+// Parse the input from a vector of strings into a hashmap holding the grid
+fn input_to_grid(input: &str) -> HashMap<(i32, i32), char> {
+    let mut grid: HashMap<(i32, i32), char> = HashMap::new();
+
+    input
+        .lines()
+        .enumerate()
+        .for_each(|(y, s)| {
+            s.chars()
+                .enumerate()
+                .for_each(|(x, ch)| {
+                    let c = match ch {
+                        '#' => '.',
+                        _ => ch,
+                    };
+
+                    grid.insert((x as i32, y as i32), c);
+        });
+    });
+    
+    grid
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let antennas = Day08.parse(&read_from_file_as_string("example1.txt")?)?;
+
+        assert_eq!(find_antinodes(&antennas.grid, antennas.rows, antennas.cols), 14);
+        assert_eq!(find_harmonic_antinodes(&antennas.grid, antennas.rows, antennas.cols), 34);
+        Ok(())
+    }
+}
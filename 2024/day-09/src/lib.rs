@@ -0,0 +1,220 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Option<u32>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input_to_diskmap(input)
+    }
+
+    fn part1(&self, diskmap: &Self::Input) -> Result<impl Display, AocError> {
+        let mut diskmap = diskmap.clone();
+        defrag(&mut diskmap);
+
+        Ok(get_checksum(&diskmap))
+    }
+
+    fn part2(&self, diskmap: &Self::Input) -> Result<impl Display, AocError> {
+        let mut diskmap = diskmap.clone();
+        let mut free_space = get_free_space_vec(&diskmap);
+        defrag_files(&mut diskmap, &mut free_space);
+
+        Ok(get_checksum(&diskmap))
+    }
+}
+
+// Iterate across all Some values in the diskmap, summing the product of the index and the file ID
+pub fn get_checksum(diskmap: &[Option<u32>]) -> u64 {
+    diskmap
+        .iter()
+        .enumerate()
+        .filter(|(_, num)| { num.is_some() })
+        .map(|(i, num)| {
+            i as u64 * num.unwrap() as u64
+        })
+        .sum()        
+}
+
+// Swap values in the diskmap to remove fragmentation as needed, one block at a time
+pub fn defrag(diskmap: &mut Vec<Option<u32>>) {
+    let mut left = 0;
+    let mut right = diskmap.len() - 1;
+
+    while left < right {
+        while diskmap[right].is_none() && left < right {
+            right -= 1;
+        }
+
+        while diskmap[left].is_some() && left < right {
+            left += 1;
+        }
+
+        if left < right {
+            diskmap.swap(right, left);
+        }
+    }
+}
+
+// Swap values in the diskmap to remove fragmentation as needed, moving whole files
+pub fn defrag_files(diskmap: &mut Vec<Option<u32>>, free_space: &mut Vec<(usize, usize)>) {
+    let mut current_val: Option<u32> = None;
+    let mut count = 0;
+    let mut start_index: usize = 0;
+
+    // Iterate across all entries in the diskmap from right to left
+    for (i, val) in diskmap.clone().iter().enumerate().rev() {
+
+        match val {
+
+            // If we've reached a Some() value (meaning we're at a file and not free space),
+            // and this is the second or subsequent file with this ID that we've read, add 
+            // to the count of file blocks and set the start_index to the current index. 
+            // Since this iterates backwards, once we get to the first block in the file,
+            // start_index will point to the beginning of the file, and count will hold its
+            // size.
+            Some(v) if Some(*v) == current_val => {
+                count += 1;
+                start_index = i;
+            },
+
+            // If we're reading a file and it's the first block from this file, check for a
+            // previously completed file and process it if it exists.
+            Some(v) => {
+                if current_val.is_some() && count > 0 {
+                    process_file(count, start_index, diskmap, free_space);
+                }
+
+                // Then start a new file sequence
+                current_val = Some(*v);
+                count = 1;
+                start_index = i;
+            },
+
+            // If we get a None value, we're reading free space. Check for a previously
+            // completed file and process it if it exists, then set the current_val and 
+            // count values to their defaults.
+            None => {
+                if current_val.is_some() && count > 0 {
+                    process_file(count, start_index, diskmap, free_space);
+                    current_val = None;
+                    count = 0;
+                }
+            }
+        }
+    }
+}
+
+// Takes a file that we've read in, find the first free space that fits it, and if a valid space exists, move the file
+// into that free space.
+fn process_file(count: usize, start_index: usize, diskmap: &mut Vec<Option<u32>>, free_space: &mut Vec<(usize, usize)>) {
+
+    // Find the first free block in free_space that's large enough to fit the file. If no such block exists, the
+    // file doesn't move.
+    if let Some(index) = free_space
+        .iter()
+        .enumerate()
+        .find(|(_, (_, space))| *space >= count)
+        .map(|(i, _)| i) 
+    {
+            // Get the start index of the free space we're moving into
+            let (free_index, _) = &mut free_space[index];
+            
+            // Swap the file and the free blocks in the diskmap vector
+            for i in 0..count {
+                let left = *free_index + i;
+                let right = start_index + i;
+
+                if left < right {
+                    diskmap.swap(left, right);
+                }
+            }
+
+            // Update the free_space vector to reflect the moves
+            *free_space = get_free_space_vec(diskmap);
+    }
+}
+
+// Takes the long input string and parses it into a diskmap
+fn parse_input_to_diskmap(input: &str) -> Result<Vec<Option<u32>>, AocError> {
+    let mut diskmap: Vec<Option<u32>> = Vec::new();
+    let mut file_id = 0;
+
+    for (i, ch) in input.trim().chars().enumerate() {
+        let n = ch.to_digit(10).ok_or_else(|| AocError::parse(1, i + 1, format!("expected a digit, got {:?}", ch)))?;
+
+        let val = match i % 2 {
+            0 => {
+                file_id += 1;
+                Some(file_id - 1)
+            },
+            _ => None,
+        };
+
+        for _ in 0..n {
+            diskmap.push(val);
+        }
+    }
+
+    Ok(diskmap)
+}
+
+// Iterates across the diskmap vec and generates a vector representing the free blocks
+fn get_free_space_vec(diskmap: &Vec<Option<u32>>) -> Vec<(usize, usize)> {
+    let mut free_space: Vec<(usize, usize)> = Vec::new();
+
+    let mut count = 0;
+    let mut start_index = 0;
+
+    for (i, num) in diskmap.iter().enumerate() {
+        match num {
+            Some(_) => {
+                if count > 0 {
+                    free_space.push((start_index, count));
+                }
+
+                count = 0;
+            },
+
+            None => {
+                if count == 0 {
+                    start_index = i;
+                }
+                count += 1;
+            }
+        }
+    }
+
+    if count > 0 {
+        free_space.push((start_index, count));
+    }
+
+    free_space
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let diskmap = Day09.parse(&read_from_file_as_string("example.txt")?)?;
+
+        let mut blocks = diskmap.clone();
+        defrag(&mut blocks);
+        assert_eq!(get_checksum(&blocks), 1928);
+
+        let mut files = diskmap.clone();
+        let mut free_space = get_free_space_vec(&files);
+        defrag_files(&mut files, &mut free_space);
+        assert_eq!(get_checksum(&files), 2858);
+        Ok(())
+    }
+}
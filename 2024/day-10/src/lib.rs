@@ -0,0 +1,78 @@
+use aoc_helpers::{ * };
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// Looks like it's time for my semi-annual re-learning of pathfinding algorithms. Went with BFS for this one.
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Input = (HashMap<(i32, i32), u8>, Vec<(i32, i32)>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Ok(input_to_grid(input))
+    }
+
+    fn part1(&self, (grid, starting_points): &Self::Input) -> Result<impl Display, AocError> {
+        Ok(find_trails(grid, starting_points))
+    }
+}
+
+// Iterates over all possible starting points in the graph ('0' nodes) and runs the BFS algorithm on each,
+// returning the total number of valid paths from 0 to 9
+pub fn find_trails(grid: &HashMap<(i32, i32), u8>, starting_points: &[(i32, i32)]) -> usize {
+    starting_points
+        .iter()
+        .map(|source| find_paths(grid, *source) )
+        .sum()
+}
+
+// Run BFS from a single source node and return the number of valid paths (0 to 9) from that source,
+// which is the number of distinct 9 nodes we can reach
+fn find_paths(grid: &HashMap<(i32, i32), u8>, source: (i32, i32)) -> usize {
+    reachable_targets(source, |node| get_neighbors(grid, *node), |node| grid.get(node) == Some(&9)).len()
+}
+
+// Get all valid neighbors from a given node
+fn get_neighbors(grid: &HashMap<(i32, i32), u8>, node: (i32, i32)) -> Vec<(i32, i32)> {
+    let orig_score: u8 = *grid.get(&node).unwrap();
+    
+    // Check all 4 cardinal directions and return a vector of results that are within the
+    // grid and have a score 1 higher than the previous node
+    Dir4::neighbours(node)
+        .filter(|neighbor| {
+            let score = grid.get(neighbor);
+            score.is_some() && *score.unwrap() == orig_score + 1
+        })
+        .collect()
+}
+
+// Parse the input into a grid (hashmap) and a list of trailheads ('0' nodes)
+fn input_to_grid(input: &str) -> (HashMap<(i32, i32), u8>, Vec<(i32, i32)>) {
+    let mut grid: HashMap<(i32, i32), u8> = HashMap::new();
+    let mut starting_points: Vec<(i32, i32)> = Vec::new();
+
+    input
+        .lines()
+        .enumerate()
+        .for_each(|(y, s)| {
+            s.chars()
+                .enumerate()
+                .for_each(|(x, ch)| {
+                    let num= ch.to_digit(10).expect("Failed to parse char to digit") as u8;
+                    grid.insert((x as i32, y as i32), num);
+
+                    if num == 0 {
+                        starting_points.push((x as i32, y as i32));
+                    }
+            })
+        });
+
+    (grid, starting_points)
+}
+
+
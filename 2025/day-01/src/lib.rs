@@ -0,0 +1,131 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, parse_turn)
+    }
+
+    fn part1(&self, turns: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_zeros(turns))
+    }
+
+    fn part2(&self, turns: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_zero_crossings(turns))
+    }
+}
+
+// Make the moves indicated in the input string and count the number of times the dial stops at 0
+pub fn count_zeros(input: &[i32]) -> u32 {
+    let mut dial: i32 = 50;
+    let mut zero_count: u32 = 0;
+
+    for &ticks in input {
+        dial += ticks;
+
+        // If the dial is outside the range [0, 99], convert it back to that range
+        if dial > 99 {
+            dial %= 100;
+
+        } else if dial < 0 {
+            dial = dial.rem_euclid(100)
+        }
+
+        // Increment the counter if the dial ends up at 0
+        if dial == 0 {
+            zero_count += 1;
+        }
+    }
+    zero_count
+}
+
+// Make the moves indicated in the input and count the number of times the dial crosses 0. I'm sure there's a more efficient
+// way to do this, and I may try to simplify it later, but we're here now.
+pub fn count_zero_crossings(input: &[i32]) -> u32 {
+    let mut dial: i32 = 50;
+    let mut zero_count: u32 = 0;
+
+    for &ticks in input {
+
+        // This will hold the number of times the dial crossed 0 per turn
+        let mut zeros: i32 = 0;
+
+        // If we're turning the dial to the left:
+        if ticks < 0 {
+
+            // When turning left, if |ticks| is at least the current dial value, we've crossed zero at least once
+            if ticks.abs() >= dial {
+
+                // This equation gives the number of times we crossed zero
+                zeros = (ticks.abs() + (100 - dial)).div_euclid(100);
+
+                // This handles the edge case where the dial started at zero and the number of crosses needs to be reduced by 1.
+                // This is only an issue when turning left
+                if dial == 0 {
+                    zeros -= 1;
+                }
+
+                // Increment the zero counter
+                zero_count += zeros as u32;
+            }
+
+        // If we're turning the dial to the right:
+        } else {
+
+            // When turning right, if ticks is at least 100 - the current dial value, we've crossed zero at least once
+            if ticks >= 100 - dial {
+                zeros = (ticks + dial).div_euclid(100);
+                zero_count += zeros as u32;
+            }
+        }
+
+        // Increment the dial by the specified number of ticks
+        dial += ticks;
+
+        // If the new dial position is outside the range [0, 99], convert it so that it's back in that range
+        if dial < 0 {
+            dial = (((dial + zeros * 100) % 100) + 100) % 100;
+
+        } else if dial > 99 {
+            dial = (dial - zeros * 100) % 100;
+        }
+    }
+    zero_count
+}
+
+// Parse a line of the input into a positive or negative integer, depending on the direction of the turn (L => negative, R => positive)
+fn parse_turn(line: &str) -> Result<i32, AocError> {
+    let sign = match line.chars().next() {
+        Some('L') => -1,
+        Some('R') => 1,
+        _ => return Err(AocError::parse(1, 1, format!("expected a turn like L68 or R14, got {:?}", line))),
+    };
+
+    let ticks = line[1..]
+        .parse::<i32>()
+        .map_err(|_| AocError::parse(1, 2, format!("expected a number of clicks after the direction, got {:?}", line)))?;
+
+    Ok(sign * ticks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let turns = Day01.parse(&read_from_file_as_string("example1.txt")?)?;
+
+        assert_eq!(count_zeros(&turns), 3);
+        assert_eq!(count_zero_crossings(&turns), 6);
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_helpers::{ * };
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_invalid(pairs))
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_invalid_repeats(pairs))
+    }
+}
+
+// My approach here was to convert each number to a string so I could easily split it into two parts. I ignore
+// any numbers with an odd number of digits, since by definition those can't fit the pattern of having being 
+// made up of two identical numbers with no leading zeros. If the number has an even number of digits, split it
+// at the midpoint and compare the two slices. If they match, add the original number to a running counter.
+
+// Now let's see how bad part 2 screws me up.
+pub fn count_invalid(pairs: &[(u64, u64)]) -> u64 {
+    let mut count: u64 = 0;
+    
+    for &(start, end) in pairs {
+        for n in start..=end {
+            let s = n.to_string();
+            if s.len() % 2 == 0 {
+                let mid = s.len() / 2;
+                let (a, b) = s.split_at(mid);
+
+                if a == b {
+                    count += n;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+// Part 2 was a bit tougher, but not as bad as I was expecting once I started working on the problem. Instead of splitting
+// the numbers in half, I checked the first n digits in the number, where n is a factor of the overall length, to see if the
+// entire number is made up of repeats of those digits. For example, for a 10-digit number, with factors 10, 5, 2, and 1, I
+// checked the first 5 digits, then the first 2, then the first 1. If any of those substrings was repeated throughout the
+// whole number, stop parsing and add the original number to the running count.  
+pub fn count_invalid_repeats(pairs: &[(u64, u64)]) -> u64 {
+    let mut count: u64 = 0;
+    
+    // Hashmap to hold the factors for each possible number of digits in the puzzle input (excluding the number
+    // itself, since there's no need to check that)
+    let factors: HashMap<usize, Vec<usize>> = HashMap::from([
+        (1, vec![]),
+        (2, vec![1]),
+        (3, vec![1]),
+        (4, vec![2, 1]),
+        (5, vec![1]),
+        (6, vec![3, 2, 1]),
+        (7, vec![1]),
+        (8, vec![4, 2, 1]),
+        (9, vec![3, 1]),
+        (10, vec![5, 2, 1])
+    ]);
+
+    for &(start, end) in pairs {
+        // Iterate through all numbers between each set of pairs
+        for num in start..=end {
+            
+            // Convert each number into a string so we can work with its digits more easily
+            let num_str = num.to_string();
+
+            // Get the number of digits in each number, as well as that number's factors
+            let len = num_str.len();
+
+            // Iterate over the relevant factors for the current number's length from the factors hashmap
+            for factor in factors.get(&len).unwrap() {
+
+                // Get the number of times we need to repeat this substring to match the number of digits in the original number,
+                // then repeat the substring that many times and compare it with the number
+                let repeats = len / factor;
+                let spl = num_str.split_at(*factor).0;
+
+                // If they match, this entry is invalid, so add it to the running count
+                if num_str == spl.repeat(repeats) {
+                    count += num;
+                    break;
+                }
+            }
+        }
+    }
+
+    count
+}
+
+// The ranges are all on one line, separated by commas, so each one's errors are moved along to where it starts
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, AocError> {
+    let range = Pattern::new("{}-{}");
+    let mut start = 0;
+
+    input
+        .trim()
+        .split(',')
+        .map(|pair| {
+            let parsed = range.parse(pair).map_err(|e| match e {
+                AocError::Parse { line, column, message } => AocError::Parse { line, column: start + column, message },
+                other => other,
+            });
+            start += pair.len() + 1;
+            parsed
+        })
+        .collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let pairs = Day02.parse(&read_from_file_as_string("example.txt")?)?;
+
+        let invalid = count_invalid_repeats(&pairs);
+        assert_eq!(invalid, 4174379265);
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, parse_bank)
+    }
+
+    // Sum the best two-digit joltage from each bank
+    fn part1(&self, banks: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(banks.iter().map(|bank| get_best_pair(bank)).sum::<u32>())
+    }
+
+    // Sum the best twelve-digit joltage from each bank
+    fn part2(&self, banks: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(banks.iter().map(|bank| get_best_joltage(bank)).sum::<u64>())
+    }
+}
+
+// A bank is a row of single-digit joltages, and part 2 picks twelve of them
+fn parse_bank(line: &str) -> Result<String, AocError> {
+    if let Some(i) = line.bytes().position(|b| !b.is_ascii_digit()) {
+        return Err(AocError::parse(1, i + 1, format!("expected a row of digits, got {:?}", line)));
+    }
+
+    if line.len() < 12 {
+        return Err(AocError::parse(1, 1, format!("a bank needs at least 12 batteries, got {:?}", line)));
+    }
+
+    Ok(line.to_string())
+}
+
+pub fn get_best_pair(bank: &str) -> u32 {
+    let max1 = bank.bytes().max().unwrap();
+    let i = bank.bytes().position(|x| x == max1).unwrap();
+
+    if i == bank.len() - 1 {
+        let max2: char = bank[..i].bytes().max().unwrap() as char;
+        return format!("{}{}", max2, max1 as char).parse::<u32>().expect("Failed to parse value to u32");
+    }
+
+    let max2: char = bank[i+1..].bytes().max().unwrap() as char;
+    format!("{}{}", max1 as char, max2).parse::<u32>().expect("Failed to parse value to u32")
+}
+
+// This one hurt my brain. My approach was similar to part 1, but instead of just going through the input string once, I used multiple iterations
+// to build the 12-digit output, finding the highest digit in the valid slice of the input string. For each iteration, the valid slice of the
+// input string starts at the index following the previous max digit (assuming that digit wasn't at the end of the input slice) and ends when
+// the distance from the end of the input equals the number of digits remaining to be filled in the output buffer.
+
+// Get the best joltage from a single bank
+pub fn get_best_joltage(input: &str) -> u64 {
+
+    // Output buffer to build the final joltage value
+    let mut output: Vec<u8> = vec![0_u8; 12];
+
+    // Start and end pointers for the input
+    let mut in_start = 0_usize;
+    let in_end = input.len() - 1;
+
+    // Start and end pointers for the output
+    let mut out_start = 0_usize;
+    let out_end = output.len() - 1;
+
+    loop {
+
+        // Tracks the number of digits remaining to be filled in the output
+        let remaining_output_digits = out_end - out_start + 1;
+
+        // Tracks the number of digits remaining to be evaluated in the input
+        let remaining_input_digits = in_end - in_start + 1;
+
+        // Tracks the last valid index in the input -- essentially, the last index where the number of digits that
+        // follow is enough to fill the rest of the output. If we go past this threshold we'll run out of digits and
+        // won't be able to fill the output.
+        let max_valid_leading_index = in_end + 1 - remaining_output_digits;
+
+        // If there's only one digit remaining in the input, add it to the last spot in the output and break out of
+        // the loop. The other checks should ensure that we don't reach this point with more than one digit remaining 
+        // to fill in the output.
+        if remaining_input_digits == 1 {
+            output[out_start] = input.as_bytes()[in_start];
+            break; 
+        }
+
+        // If there's only one digit left to fill in the output, fill it with the max digit in the current valid input 
+        // slice and break out of the loop.
+        if remaining_output_digits == 1 {
+            output[out_start] = input[in_start ..= in_end].bytes().max().unwrap();
+            break;
+        }
+
+        // If the number of remaining input digits equals the number of output digits left to fill, we fill the remainng
+        // output digits with the remaining input digits in order and break out of the loop.
+        if remaining_input_digits == remaining_output_digits {
+
+            let remaining_in = &input.as_bytes()[in_start ..= in_end];
+            let remaining_out = &mut output[out_start ..= out_end];
+
+            remaining_out.copy_from_slice(remaining_in);
+            break;
+        }
+
+        // If none of those ending cases are true, find the max digit in the valid input slice and get its index.
+        // The first one wins a tie, since that leaves the most digits to choose from afterwards.
+        let max = input[in_start ..= max_valid_leading_index].bytes().max().unwrap();
+        let max_i = input[in_start ..= max_valid_leading_index].bytes().position(|x| x == max).unwrap();
+
+        // Add the max we just calculated to the first empty spot in the output buffer, increment the output buffer
+        // start pointer, and add max_i + 1 to the input start pointer (to make the next cycle's valid slice start
+        // with the index following the max we just found).
+        output[out_start] = max;
+        out_start += 1;
+        in_start += max_i + 1;
+    }
+    
+    // Convert the output buffer to a string and then to a u64 and return it
+    String::from_utf8(output).unwrap().parse::<u64>().expect("Failed to parse final output string to int")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() -> Result<(), Box<dyn std::error::Error>> {
+        let banks = Day03.parse(&read_from_file_as_string("example.txt")?)?;
+
+        assert_eq!(banks.iter().map(|bank| get_best_pair(bank)).sum::<u32>(), 357);
+        assert_eq!(banks.iter().map(|bank| get_best_joltage(bank)).sum::<u64>(), 3121910778619);
+        Ok(())
+    }
+}
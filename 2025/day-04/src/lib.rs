@@ -0,0 +1,72 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Input = Grid<Cell>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Grid::parse(input)
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(get_accessible_rolls(grid).len())
+    }
+
+    fn part2(&self, grid: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(remove_accessible_rolls(grid.clone()))
+    }
+}
+
+// Keep removing every accessible roll until there aren't any left, and count how many came out
+pub fn remove_accessible_rolls(mut grid: Grid<Cell>) -> u32 {
+    let mut count = 0_u32;
+
+    loop {
+        let accessible = get_accessible_rolls(&grid);
+        count += accessible.len() as u32;
+        
+        if accessible.is_empty() { break; }
+
+        update_grid(&mut grid, accessible);
+    }
+
+    count
+}
+
+fn update_grid(grid: &mut Grid<Cell>, removed: Vec<(i32, i32)>) {
+    removed.iter().for_each(|(x, y)| { grid.set(*x, *y, Cell::Empty); });
+}
+
+pub fn get_accessible_rolls(grid: &Grid<Cell>) -> Vec<(i32, i32)> {
+    grid.find_all(&Cell::Roll)
+        .filter(|pos| check_all_dirs(grid, *pos) < 4)
+        .collect()
+}
+
+// Count the rolls around a position. Off the edge of the grid counts as empty, which get() takes care of.
+fn check_all_dirs(grid: &Grid<Cell>, pos: (i32, i32)) -> u32 {
+    Dir8::neighbours(pos)
+        .filter(|(x, y)| grid.get(*x, *y) == Some(&Cell::Roll))
+        .count() as u32
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Cell { Empty, Roll }
+
+impl TryFrom<char> for Cell {
+    type Error = ();
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            '.' => Ok(Cell::Empty),
+            '@' => Ok(Cell::Roll),
+            _ => Err(())
+        }
+    }
+}
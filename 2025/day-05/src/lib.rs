@@ -0,0 +1,91 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day05;
+
+pub struct Inventory {
+    pub ranges: RangeSet<u64>,
+    pub numbers: Vec<u64>,
+}
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Input = Inventory;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, inventory: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(check_values(&inventory.ranges, &inventory.numbers))
+    }
+
+    fn part2(&self, inventory: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_fresh(&inventory.ranges))
+    }
+}
+
+// My approach here was pretty straightforward: just check each given number against the given ranges to see if it was included in any.
+// I decided to sort and merge the given ranges here to make computation a bit less expensive, especially because I figured part 2 would
+// require more expensive computations and might make it infeasible without a bunch of optimization. That ended up not being the case,
+// but merging the ranges here still made part 2 wildly easy.   
+
+// Counts the number of fresh ingredients by checking each number against the given ranges of values. This runs in about 2ms on my end,
+// but I imagine part 2 is gonna throw some curveball that makes this approach not feasible. But we'll see.
+// **UPDATE: nope, part 2 was way easier. The only curveball was that the given ranges have some overlap, which wasn't an issue since I
+// merged the ranges in this part. Winning.
+pub fn check_values(ranges: &RangeSet<u64>, numbers: &[u64]) -> u32 {
+    numbers
+        .iter()
+        .filter(|n| ranges.contains(**n))
+        .count().try_into().unwrap()
+}
+
+// Merging the ranges in part 1 really paid off here. Just a matter of summing the number of IDs in each range. The bulk of this code is for
+// reading in and parsing the ranges, the actual counting is basically a one-liner.
+
+// Count the number of IDs considered fresh by summing the total number of IDs in each range
+pub fn count_fresh(ranges: &RangeSet<u64>) -> u64 {
+    ranges.len()
+}
+
+// Runs on the sample input rather than the real puzzle input when '-- --example' is added to the cargo run command.
+// Game changer.
+fn parse_input(input: &str) -> Result<Inventory, AocError> {
+    let input: Vec<String> = input.lines().map(String::from).collect();
+
+    // Split the input vector by the empty line that marks the move from ranges to numbers
+    let sections: Vec<&[String]> = input.split(|line| line.is_empty()).collect();
+    let [ranges, numbers] = sections[..] else {
+        return Err(AocError::parse(1, 1, "expected the ranges and the IDs, separated by a blank line"));
+    };
+
+    // The IDs start after the ranges and the blank line
+    let first_id_line = ranges.len() + 2;
+    let ranges = parse_ranges(ranges)?;
+    let numbers = parse_numbers(numbers, first_id_line)?;
+
+    Ok(Inventory { ranges, numbers })
+}
+
+// Takes the vector of range strings and parses it into a set of ranges. The set sorts the ranges and merges any
+// overlapping ones as they go in, which makes this whole thing a little more efficient.
+fn parse_ranges(input: &[String]) -> Result<RangeSet<u64>, AocError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, s)| s.parse::<Interval<u64>>().map_err(|e| on_line(e, i + 1)))
+        .collect()
+}
+
+// Parses the number strings into u64s. They don't start on the first line, so that's passed in for the errors.
+fn parse_numbers(input: &[String], first_line: usize) -> Result<Vec<u64>, AocError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, s)| s.parse::<u64>().map_err(|_| AocError::parse(first_line + i, 1, format!("expected an ingredient ID, got {:?}", s))))
+        .collect()
+}
@@ -0,0 +1,160 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+// This one was a wild ride. I completely misunderstood the format at first. My misunderstanding worked
+// fine on part 1, but part 2 screwed me. I had to start from scratch when I realized it was about the
+// specific column the character was in and not the digit position within the number. But the important
+// thing is that we got there.
+
+#[derive(Default)]
+pub struct Day06;
+
+// The two parts read the numbers out of the rows differently, so both readings are done up front
+pub struct Worksheet {
+    pub operators: Vec<Operator>,
+    pub by_rows: Vec<Vec<u64>>,
+    pub by_columns: Vec<Vec<u64>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        let mut rows: Vec<&str> = input.lines().collect();
+        let op_str = rows.pop().unwrap_or_default();
+
+        // Anything but digits and spaces in a number row is a mistake, and catching it here means the readings
+        // below only ever see numbers
+        for (y, row) in rows.iter().enumerate() {
+            if let Some(x) = row.find(|ch: char| !ch.is_ascii_digit() && ch != ' ') {
+                return Err(AocError::parse(y + 1, x + 1, format!("expected only digits and spaces, got {:?}", row)));
+            }
+        }
+
+        // Get the operators for each column by grabbing the last row in the input
+        let operators: Vec<Operator> = op_str
+            .char_indices()
+            .filter(|(_, ch)| *ch != ' ')
+            .map(|(x, ch)| match ch {
+                '+' => Ok(Operator::Add),
+                '*' => Ok(Operator::Multiply),
+                _ => Err(AocError::parse(rows.len() + 1, x + 1, format!("expected '+' or '*', got {:?}", ch))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let by_rows = read_rows(&rows)?;
+        let by_columns = read_columns(&rows)?;
+
+        if by_rows.len() != operators.len() || by_columns.len() != operators.len() {
+            let message = format!("{} problems but {} operators", by_rows.len(), operators.len());
+            return Err(AocError::parse(rows.len() + 1, 1, message));
+        }
+
+        Ok(Worksheet { operators, by_rows, by_columns })
+    }
+
+    fn part1(&self, sheet: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(do_homework(&sheet.operators, &sheet.by_rows))
+    }
+
+    fn part2(&self, sheet: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(do_homework(&sheet.operators, &sheet.by_columns))
+    }
+}
+
+// Take the sum or product of the numbers in each column and then sum them together
+pub fn do_homework(operators: &[Operator], numbers: &[Vec<u64>]) -> u64 {
+    operators
+        .iter()
+        .zip(numbers.iter())
+        .map(|(op, nums)| -> u64 {
+            match op {
+                Operator::Add => nums.iter().sum(),
+                Operator::Multiply => nums.iter().product(),
+            }
+        })
+        .sum()
+}
+
+// Part 1 reads the numbers the way a human would, one per whitespace-separated token, and groups them by column
+pub fn read_rows(rows: &[&str]) -> Result<Vec<Vec<u64>>, AocError> {
+    let numbers_transposed: Vec<Vec<u64>> = parse_lines(&rows.join("\n"), ints)?;
+
+    Ok(transpose(&numbers_transposed))
+}
+
+// Part 2 reads them cephalopod-style, one number per character column
+pub fn read_columns(rows: &[&str]) -> Result<Vec<Vec<u64>>, AocError> {
+
+    // The puzzle says numbers are delineated by a column of whitespace all the way down, so
+    // we find those whitespace columns and add their indices to a list.
+    let separators = blank_columns(rows);
+
+    // Splits the strings at the indices we just calculated and collect them into a vector of
+    // vectors. Because we're looking at it column-wise, we'll need to transpose this vector
+    // in order to get the numbers we need. We keep the numbers as strings here so we can retain
+    // the whitespace for figuring out the alignment.
+    let n_strings_transposed: Vec<Vec<String>> = rows
+        .iter()
+        .map(|s| split_columns(s, &separators)
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+        )
+        .collect();
+
+    // Transpose that vector we just created
+    let n_strings = transpose(&n_strings_transposed);
+
+    // Convert to the cephalapod notation from part 2
+    convert_to_cephalopod(&n_strings)
+}
+
+// This takes the 2D vector of strings (with whitespace retained), calculates alignment,
+// and parses the numbers read column-wise into ints. A row that stops short just has
+// nothing to add to the columns past its end.
+fn convert_to_cephalopod(n_strings: &[Vec<String>]) -> Result<Vec<Vec<u64>>, AocError> {
+    n_strings
+        .iter()
+        .map(|group| {
+            let width = group.iter().map(String::len).max().unwrap_or(0);
+
+            (0..width)
+                .map(|i| {
+                    let num: String = group
+                        .iter()
+                        .filter_map(|s| s.as_bytes().get(i).map(|&b| b as char))
+                        .filter(|ch| !ch.is_whitespace())
+                        .collect();
+
+                    num.parse::<u64>().map_err(|_| AocError::parse(1, 1, format!("column {:?} of a problem has no digits", i + 1)))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Transpose a 2D vector
+fn transpose<T: Clone>(input: &[Vec<T>]) -> Vec<Vec<T>> {
+    let rows = input.len();
+    let cols = input.first().map_or(0, |r| r.len());
+
+    let mut result = vec![Vec::with_capacity(rows); cols];
+
+    for row in input {
+        for (i, val) in row.iter().enumerate() {
+            result[i].push(val.clone());
+        }
+    }
+
+    result
+}
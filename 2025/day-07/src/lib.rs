@@ -0,0 +1,202 @@
+use aoc_helpers::{ * };
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Manifold::new(input)
+    }
+
+    fn part1(&self, manifold: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(manifold.count_splits())
+    }
+
+    fn part2(&self, manifold: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(manifold.count_timelines())
+    }
+}
+
+// Struct to handle the manifold. The beams and splitters each part tracks live in that part's own method.
+pub struct Manifold { 
+    grid: Vec<Vec<Node>>,
+    start: (usize, usize),
+}
+
+impl Manifold {
+    pub fn new(input: &str) -> Result<Self, AocError> {
+        let mut grid: Vec<Vec<Node>> = Vec::new();
+        let mut start: Option<(usize, usize)> = None;
+
+        // Parse the input vector into a 2D grid
+        for (row, s) in input.lines().enumerate() {
+            let mut column: Vec<Node> = Vec::new();
+
+            for (col, ch) in s.chars().enumerate() {
+                let node = Node::try_from(ch)
+                    .map_err(|InvalidNodeError(ch)| AocError::parse(row + 1, col + 1, format!("unexpected character {:?}", ch)))?;
+                if node == Node::Start {
+                    start = Some((col, row));
+                }
+                column.push(node);
+            }
+
+            grid.push(column);
+        }
+
+        let start = start.ok_or_else(|| AocError::parse(1, 1, "there's no S in the manifold"))?;
+        Ok(Self { grid, start })
+    }
+
+    // Start the machine and track the number of splits
+    pub fn count_splits(&self) -> u32 {
+        let mut splits: HashSet<(usize, usize)> = HashSet::new();
+        let mut split_count: u32 = 0;
+        let mut beams: Vec<(usize, usize)> = Vec::from([self.start]);
+        let mut visited: HashSet<(usize, usize)> = HashSet::from([self.start]);
+
+        // Loop until there are no more active beams
+        while !beams.is_empty() {
+
+            // This will hold the new beams that are created this iteration of the outer loop
+            let mut new_beams: Vec<(usize, usize)> = Vec::new();
+
+            // Loop through all currently active beams. This loop will fully process each beam until it hits a splitter
+            // and creates 2 new beams or until it reaches the end of the grid. The new_beams vector will be populated 
+            // with the beams created during this loop, which will be copied into beams at the end of the outer loop
+            for beam in &beams {
+
+                // This will hold the running coordinates for the current beam as it traverses the grid
+                let (x, mut y) = *beam;
+
+                // Process the current beam until it splits or reaches the end of the grid, checking each position
+                // to see if it's empty space or a splitter and proceeding accordingly
+                while let Some(node) = self.check_node((x, y)) {
+                    match node {
+                        Node::Empty | Node::Start => {
+                            y += 1;
+                            visited.insert((x, y));
+                        },
+
+                        Node::Splitter => {
+                            
+                            // If we haven't counted this splitter yet, increment the split_count variable to track the 
+                            // total number of splits 
+                            if splits.insert((x, y)) {
+                                split_count += 1;
+                            }
+
+                            // Create a new beam to the left, assuming there isn't already a beam there and the new
+                            // position isn't outside the bounds of the grid
+                            if let Some(x_new) = x.checked_sub(1)
+                                && self.check_node((x_new, y)).is_some()
+                                && !visited.contains(&(x_new, y))
+                                && !new_beams.contains(&(x_new, y))
+                            {
+                                new_beams.push((x_new, y));
+                            }
+
+                            // Create a new beam to the right with the same constraints
+                            if self.check_node((x + 1, y)).is_some()
+                                && !visited.contains(&(x + 1, y))
+                                && !new_beams.contains(&(x + 1, y))
+                            {
+                                new_beams.push((x + 1, y));
+                            }
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Since we've fully processed all beams that were in beams, the newly created beams get placed into that
+            // vector to be processed next iteration of the loop. If we didn't create any new beams this iteration, beams 
+            // will be empty and the loop will end.
+            beams = new_beams;
+        }
+
+        // Return the total number of times the beam split
+        split_count
+    }
+
+    // My implementation for part 2 was a lot simpler than my implementation for part 1, which makes me think I overengineered
+    // part 1. This was just a DFS binary tree traversal problem
+    pub fn count_timelines(&self) -> u64 {
+
+        // Each beam position gets its timeline count worked out once: a beam that runs off the bottom of the
+        // grid is one timeline, and a beam that hits a splitter has as many as its two halves put together
+        memoize(self.start, |&pos, timelines| {
+            let next_paths = self.step(pos);
+
+            if next_paths.is_empty() {
+                1
+            } else {
+                next_paths.into_iter().map(timelines).sum()
+            }
+        })
+    }
+
+    // Step through one path in the graph until reaching a splitter or the end of the graph
+    fn step(&self, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        let (x, mut y) = (pos.0, pos.1 + 1);
+
+        let mut new_paths: Vec<(usize, usize)> = Vec::new();
+
+        while let Some(node) = self.check_node((x, y)) {
+            match node {
+                Node::Empty | Node::Start => y += 1,
+                Node::Splitter => {
+                    if let Some(x_new) = x.checked_sub(1) && self.check_node((x_new, y)).is_some() {
+                        new_paths.push((x_new, y));
+                    }
+
+                    if self.check_node((x + 1, y)).is_some() {
+                        new_paths.push((x + 1, y));
+                    }
+
+                    break;
+                },
+            }
+        }
+        new_paths
+    }
+
+    // Checks an (x, y) position and returns either the Node enum at that position, or None if that index is outside the grid
+    fn check_node(&self, (x, y): (usize, usize)) -> Option<Node> {
+        if let Some(node) = self.grid.get(y).and_then(|row| row.get(x)).copied() {
+            return Some(node);
+        }
+        None
+    }
+
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Node {
+    Start,
+    Empty,
+    Splitter,
+}
+
+impl TryFrom<char> for Node {
+    type Error = InvalidNodeError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'S' => Ok(Node::Start),
+            '.' => Ok(Node::Empty),
+            '^' => Ok(Node::Splitter),
+            _ => Err(InvalidNodeError(ch)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InvalidNodeError(char);
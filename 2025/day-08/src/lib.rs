@@ -0,0 +1,85 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+// My approach here was originally to create a Pair struct that holds the indices of two nodes and their distance
+// apart, then use a binary heap to collect every single pair and sort by shortest distance so I could pop the nodes
+// in order by closest together. Now a k-d tree hands out the pairs closest first without building all of them,
+// and a union-find structure handles adding them to circuits.
+
+// Part 2 only required a little bit of re-working thanks to the union-find data structure I used,
+// which I definitely knew of off the top of my head and didn't have to do any research at all to
+// find. 
+
+// Okay, that's false, I had never heard of a union-find before this, but that's the cool thing 
+// about AoC. I'm learning all sorts of cool shit.
+
+pub struct Day08 {
+
+    // The number of pairs part 1 connects
+    pub pairs: usize,
+}
+
+impl Default for Day08 {
+    fn default() -> Self {
+        Self { pairs: 1000 }
+    }
+}
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Input = Vec<Point3>;
+
+    // The number of pairs to connect depends on whether we're looking at the real puzzle input or
+    // the example input. The example specified 10 pairs, and the real input asks for 1000. Either
+    // can be overridden with '--set pairs=N'.
+    fn from_args(args: &RunArgs) -> Result<Self, AocError> {
+        Ok(Self { pairs: args.param("pairs", 10, 1000)? })
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part1(&self, boxes: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(find_circuits(boxes, self.pairs))
+    }
+
+    fn part2(&self, boxes: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(connect_all(boxes))
+    }
+}
+
+// Connects the closest `pairs` pairs and returns the product of the three largest circuits
+pub fn find_circuits(boxes: &[Point3], pairs: usize) -> u32 {
+    let mut circuits = DisjointSet::new(boxes.len());
+
+    // Take each pair, sorted by distance ascending, and add the two nodes to the same circuit (which
+    // does nothing if they're already in one together).
+    for (i, j, _) in closest_pairs(boxes).take(pairs) {
+        circuits.union(i, j);
+    }
+
+    // The disjoint set keeps track of how big each circuit is, so it's just a matter of asking it for
+    // the three largest
+    circuits.largest(3).iter().product::<usize>() as u32
+}
+
+// Uses a disjoint set to keep track of the number of circuits and the elements in each
+pub fn connect_all(boxes: &[Point3]) -> u64 {
+    let mut circuits = DisjointSet::new(boxes.len());
+
+    // Loop until the number of circuits is 1 -- in other words, until all nodes are in the same
+    // circuit. When that happens, return the product of the X coordinates for the two nodes that
+    // caused all nodes to be connected together.
+    for (i, j, _) in closest_pairs(boxes) {
+        circuits.union(i, j);
+
+        if circuits.component_count() == 1 {
+            return (boxes[i].x * boxes[j].x) as u64;
+        }
+    }
+
+    0
+}
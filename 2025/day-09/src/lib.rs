@@ -0,0 +1,167 @@
+use aoc_helpers::{ * };
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        Pattern::new("{},{}").parse_lines(input)
+    }
+
+    fn part1(&self, points: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(check_pairs(get_pairs(points)))
+    }
+
+    fn part2(&self, points: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(find_best_rect(points))
+    }
+}
+
+pub fn check_pairs(mut heap: BinaryHeap<Pair>) -> u64 {
+    let mut parsed: Vec<(u32, u32)> = Vec::new();
+
+    for _ in 0..heap.len() {
+        if let Some(val) = heap.pop() {
+            parsed.push((val.width, val.height));
+        }
+    }
+
+    let max = parsed.iter().max_by_key(|(w, h)| w * h).unwrap();
+    max.0 as u64 * max.1 as u64
+}
+
+// Every rectangle two of the points make, by width and height
+fn get_pairs(points: &[(usize, usize)]) -> BinaryHeap<Pair> {
+    let mut heap: BinaryHeap<Pair> = BinaryHeap::new();
+
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let (x1, y1) = (points[i].0 as u32, points[i].1 as u32);
+            let (x2, y2) = (points[j].0 as u32, points[j].1 as u32);
+
+            let width = ((x2 as i32 - x1 as i32).abs() + 1) as u32;
+            let height = ((y2 as i32 - y1 as i32).abs() + 1) as u32;
+
+            heap.push(Pair { width, height });
+        }
+    }
+
+    heap
+}
+
+// This one nearly broke me. My first go at it built the polygon's interior row by row with a hand-rolled ray tracer,
+// which took ~11s and 3 days of struggling. Asking the polygon directly whether each rectangle fits is a lot simpler.
+
+// Essentially this puzzle gives a polygon formed by connecting each of the points in the input. And the goal is to go through
+// each rectangle created by connecting any two input points together, starting with the largest rectangle and moving down the
+// list by descending area, and checks to see if the rectangle is entirely within the polygon.
+
+// Goes through each Rect of points (i.e., each rectangle) from largest area to smallest, checking to see if the rectangle is
+// contained within the polygon. If it is, return its area, otherwise check the next one.
+pub fn find_best_rect(points: &[(usize, usize)]) -> u64 {
+    let polygon = Polygon::new(points.iter().map(|&(x, y)| (x as i64, y as i64)).collect());
+    let mut rects = get_rects(points);
+
+    while let Some(rect) = rects.pop() {
+        let (x1, x2) = rect.x_span;
+        let (y1, y2) = rect.y_span;
+
+        if polygon.contains_rect((x1 as i64, y1 as i64), (x2 as i64, y2 as i64)) {
+            return rect.area;
+        }
+    }
+
+    0
+}
+
+// Parse all possible rectangles into a binary heap so the biggest come out first
+fn get_rects(points: &[(usize, usize)]) -> BinaryHeap<Rect> {
+    let mut rectangles: BinaryHeap<Rect> = BinaryHeap::new();
+
+    for i in 0..points.len() {
+        for j in (i + 1)..points.len() {
+            let (x1, y1) = points[i];
+            let (x2, y2) = points[j];
+
+            let (x_min, x_max) = (x1.min(x2), x1.max(x2));
+            let (y_min, y_max) = (y1.min(y2), y1.max(y2));
+
+            let width = x_max - x_min + 1;
+            let height = y_max - y_min + 1;
+
+            let area = width as u64 * height as u64;
+
+            let x_span = (x_min, x_max);
+            let y_span = (y_min, y_max);
+
+            rectangles.push(Rect {
+                area,
+                x_span,
+                y_span,
+            })
+        }
+    }
+
+    rectangles
+}
+
+#[derive(Debug)]
+pub struct Rect {
+    area: u64,
+    x_span: (usize, usize),
+    y_span: (usize, usize),
+}
+
+impl Eq for Rect {}
+
+impl PartialEq for Rect {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area
+    }
+}
+
+impl Ord for Rect {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.area.cmp(&other.area)
+    }
+}
+
+impl PartialOrd for Rect {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+pub struct Pair { 
+    width: u32, 
+    height: u32, 
+}
+
+impl Eq for Pair {}
+
+impl PartialEq for Pair {
+    fn eq(&self, other: &Self) -> bool {
+        self.width.eq(&other.width)
+    }
+}
+
+impl Ord for Pair {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.width.partial_cmp(&other.width).unwrap()
+    }
+}
+
+impl PartialOrd for Pair {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_helpers::{ * };
+use std::fmt::Display;
+
+// This one was fun. The current state of the lights, the target state, and the buttons can all be represented as bitsets, with
+// each light representing a single bit. That makes toggling lights just a matter of XORing the current state with the button.
+
+#[derive(Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_lines(input, Machine::from_line)
+    }
+
+    fn part1(&self, machines: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(machines.iter().map(|m| m.push_buttons()).sum::<u32>())
+    }
+
+    fn part2(&self, machines: &Self::Input) -> Result<impl Display, AocError> {
+        let mut total = 0;
+
+        for (i, machine) in machines.iter().enumerate() {
+            total += machine.configure_joltage().ok_or_else(|| AocError::Solution {
+                year: Self::YEAR,
+                day: Self::DAY,
+                part: 2,
+                message: format!("machine {} can't reach its joltage targets", i + 1),
+            })?;
+        }
+
+        Ok(total)
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    target: BitSet,
+    buttons: Vec<BitSet>,
+    joltage: Vec<u32>,
+}
+
+impl Machine {
+    
+    // Creates a new instance of Machine from a line of the puzzle input, which looks like
+    // [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+    pub fn from_line(line: &str) -> Result<Self, AocError> {
+        let (lights, buttons, joltage): (String, String, String) = Pattern::new("[{}] {} {{}}").parse(line)?;
+
+        // Store the desired state as a bitset where each bit represents a light, and each button as a
+        // bitset of the lights it toggles. There's no telling how many lights a machine has, so these grow to fit.
+        let target = BitSet::parse_lights(&lights)?;
+        let buttons: Vec<BitSet> = buttons
+            .split_ascii_whitespace()
+            .map(BitSet::parse_indices)
+            .collect::<Result<_, AocError>>()?;
+
+        // Part 1 only needs the lights, part 2 only needs the joltages
+        let joltage: Vec<u32> = ints(&joltage)?;
+
+        Ok(Self { target, buttons, joltage })
+    }
+
+    // Pressing a button twice undoes it, so the answer is the smallest set of buttons, each pressed once, whose
+    // toggles add up to the target. Try every set of one button, then every set of two, and so on.
+    pub fn push_buttons(&self) -> u32 {
+        let all = u64::from_indices(0..self.buttons.len());
+
+        (0..=self.buttons.len())
+            .find(|&presses| {
+                all.subsets_of_size(presses)
+                    .any(|pick| pick.ones().fold(BitSet::new(), |state, i| &state ^ &self.buttons[i]) == self.target)
+            })
+            .unwrap_or(0) as u32
+    }
+
+    // Not ashamed to admit this one took me a while. I went through 4 or 5 different algorithms that worked great on the example
+    // input but ballooned out of control on the real input, and then ran a parity trick from Reddit for a while that took ~14s.
+    //
+    // It's really a system of linear equations: each counter's target is the sum of the presses of every button wired to it. So
+    // with one variable per button, row reduction pins most of the presses down in terms of a few free ones (usually no more than
+    // two or three), and no button can be pressed more times than the smallest target it feeds into. Trying every value of the
+    // free presses within those bounds and keeping the smallest whole, non-negative total is quick.
+    pub fn configure_joltage(&self) -> Option<u64> {
+        let a: Vec<Vec<i64>> = (0..self.joltage.len())
+            .map(|counter| self.buttons.iter().map(|button| button.get(counter) as i64).collect())
+            .collect();
+        let b: Vec<i64> = self.joltage.iter().map(|&j| j as i64).collect();
+
+        let upper: Vec<i64> = self
+            .buttons
+            .iter()
+            .map(|button| button.ones().map(|counter| b.get(counter).copied().unwrap_or(0)).min().unwrap_or(0))
+            .collect();
+
+        let presses = SolutionSpace::new(&a, &b)?.min_sum_nonnegative(&upper)?;
+        Some(presses.iter().sum::<i64>() as u64)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn more_lights_than_a_u64() -> Result<(), Box<dyn std::error::Error>> {
+        let machine = Machine::from_line(&format!("[{}] (0) (1,64) (2,63) {{1,1}}", "#".repeat(65)))?;
+
+        assert_eq!(machine.target.count(), 65);
+        assert!(machine.buttons[1].get(64));
+        Ok(())
+    }
+}
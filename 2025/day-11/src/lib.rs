@@ -0,0 +1,67 @@
+use aoc_helpers::{ * };
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+
+    type Input = HashMap<String, Vec<String>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, graph: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_paths(graph))
+    }
+
+    fn part2(&self, graph: &Self::Input) -> Result<impl Display, AocError> {
+        Ok(count_server_paths(graph))
+    }
+}
+
+// This was a nice little break after the hell that was day 10. Basically a recursive graph traversal problem.
+
+// Count every path from "you" to "out"
+pub fn count_paths(graph: &HashMap<String, Vec<String>>) -> u64 {
+    aoc_helpers::count_paths("you", |device| outputs(graph, device), |device| *device == "out")
+}
+
+// This part adds the requirement to track a state (have we seen "dac" and "fft"?), and seems to add a ton more possible paths to traverse. The state
+// thing was pretty simple, just track whether we've seen either of those nodes in the recursion. The extra paths required doing some memoization to
+// limit the search area.
+
+// Each node carries whether we've passed "dac" and "fft" on the way to it, so the path counter's memo
+// keeps them apart. A path only counts if it reaches "out" having seen both.
+pub fn count_server_paths(graph: &HashMap<String, Vec<String>>) -> u64 {
+    aoc_helpers::count_paths(
+        ("svr", false, false),
+        |&(device, dac, fft)| {
+            outputs(graph, device).map(move |next| (next, dac || next == "dac", fft || next == "fft"))
+        },
+        |&(device, dac, fft)| device == "out" && dac && fft,
+    )
+}
+
+// The devices a device feeds into. One with no outputs is a dead end.
+fn outputs<'a>(graph: &'a HashMap<String, Vec<String>>, device: &str) -> impl Iterator<Item = &'a str> {
+    graph.get(device).into_iter().flatten().map(String::as_str)
+}
+
+// Parse the puzzle (or example) input into a hashmap to form the graph. Part 2 has its own example (example2.txt),
+// so run it with '-- --example 2'
+fn parse_input(input: &str) -> Result<HashMap<String, Vec<String>>, AocError> {
+    let device = Pattern::new("{}:{}");
+
+    let lines: Vec<(String, String)> = device.parse_lines(input)?;
+    let graph = lines
+        .into_iter()
+        .map(|(device, outputs)| (device, outputs.split_ascii_whitespace().map(String::from).collect()))
+        .collect();
+
+    Ok(graph)
+}
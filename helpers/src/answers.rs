@@ -0,0 +1,340 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::error::AocError;
+use crate::input::{ read_from_file_as_string, Config };
+use crate::registry::{ toml_error, Registry, SolutionEntry };
+use crate::runner::run_part;
+use crate::source::{ InputSource, RunArgs };
+
+
+// The answers AoC accepted, so a change to a shared helper can be checked against every day that uses it. They live
+// in a TOML file at the repo root, one table per day, with the answers to that day's examples in tables named after
+// the example files:
+//
+//     [2022.day01]
+//     part1 = 24000
+//     part2 = "45000"
+//
+//     [2022.day01.example1_1]
+//     part1 = 24000
+//
+// Answers can be written as numbers or strings (some puzzles want letters), and either way they're compared as text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u16, u8, u8), String>,
+
+    // Keyed by example name as well, so "1_1" is example1_1.txt
+    examples: BTreeMap<(u16, u8, String, u8), String>,
+}
+
+impl Answers {
+
+    // A missing file just means nothing's been recorded yet
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        Answers::from_toml(&read_from_file_as_string(path)?)
+    }
+
+    pub fn from_toml(contents: &str) -> Result<Self, AocError> {
+        let years: BTreeMap<String, BTreeMap<String, toml::Table>> =
+            toml::from_str(contents).map_err(|e| toml_error(contents, e))?;
+
+        let mut answers = Answers::default();
+
+        for (year_key, days) in years {
+            let year = year_key.parse::<u16>().map_err(|_| bad_key(&year_key, "a year"))?;
+
+            for (day_key, parts) in days {
+                let day = day_key
+                    .strip_prefix("day")
+                    .unwrap_or(&day_key)
+                    .parse::<u8>()
+                    .map_err(|_| bad_key(&day_key, "a day like day01"))?;
+
+                for (key, value) in parts {
+                    if let Some(name) = key.strip_prefix("example") {
+                        let toml::Value::Table(parts) = value else {
+                            return Err(bad_key(&key, "a table of example answers"));
+                        };
+
+                        for (part_key, value) in parts {
+                            let (part, answer) = part_answer(&format!("{}.{}.{}", year_key, day_key, key), part_key, value)?;
+                            answers.insert_example(year, day, name, part, answer);
+                        }
+                    } else {
+                        let (part, answer) = part_answer(&format!("{}.{}", year_key, day_key), key, value)?;
+                        answers.insert(year, day, part, answer);
+                    }
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, answer: impl Into<String>) {
+        self.answers.insert((year, day, part), answer.into());
+    }
+
+    pub fn get_example(&self, year: u16, day: u8, name: &str, part: u8) -> Option<&str> {
+        self.examples.get(&(year, day, name.to_string(), part)).map(String::as_str)
+    }
+
+    pub fn insert_example(&mut self, year: u16, day: u8, name: &str, part: u8, answer: impl Into<String>) {
+        self.examples.insert((year, day, name.to_string(), part), answer.into());
+    }
+
+    // The names of the examples with an answer recorded for this part, in order
+    pub fn examples(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &str> {
+        self.examples
+            .keys()
+            .filter(move |(y, d, _, p)| (*y, *d, *p) == (year, day, part))
+            .map(|(_, _, name, _)| name.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len() + self.examples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty() && self.examples.is_empty()
+    }
+}
+
+fn part_answer(table: &str, part_key: String, value: toml::Value) -> Result<(u8, String), AocError> {
+    let part = match part_key.as_str() {
+        "part1" => 1,
+        "part2" => 2,
+        _ => return Err(bad_key(&part_key, "part1 or part2")),
+    };
+
+    let answer = match value {
+        toml::Value::Integer(n) => n.to_string(),
+        toml::Value::String(s) => s.trim().to_string(),
+        other => {
+            let entry = format!("{}.{} = {}", table, part_key, other);
+            return Err(bad_key(&entry, "a number or a string"));
+        },
+    };
+
+    Ok((part, answer))
+}
+
+fn bad_key(key: &str, expected: &str) -> AocError {
+    AocError::parse(1, 1, format!("expected {} in the answers file, got {:?}", expected, key))
+}
+
+// Why a part wasn't checked. Neither is a failure: inputs are personal and mostly aren't checked in, so a fresh clone
+// has few of them, and days solved before the answers file existed won't have anything recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Skip {
+    NoInput,
+    NoAnswer,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Passed,
+    Failed { expected: String, actual: String },
+
+    // The solution didn't build, crashed or printed nothing
+    Errored(AocError),
+
+    Skipped(Skip),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Failed { .. } | Outcome::Errored(_))
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Outcome::Passed => "ok".to_string(),
+            Outcome::Failed { expected, actual } => format!("FAILED: expected {}, got {}", expected, actual),
+            Outcome::Errored(e) => format!("FAILED: {}", e),
+            Outcome::Skipped(Skip::NoInput) => "skipped, no input".to_string(),
+            Outcome::Skipped(Skip::NoAnswer) => "skipped, no recorded answer".to_string(),
+        }
+    }
+}
+
+// One line of the check report. `example` names the example that was run, if it wasn't the puzzle input.
+#[derive(Debug)]
+pub struct CheckRow {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub example: Option<String>,
+    pub outcome: Outcome,
+}
+
+impl CheckRow {
+    fn label(&self) -> String {
+        let label = format!("{} {:02}/{}", self.year, self.day, self.part);
+
+        match &self.example {
+            Some(name) => format!("{} example{}", label, name),
+            None => label,
+        }
+    }
+}
+
+// Runs one part against its puzzle input and compares what it prints with the recorded answer. The cached input wins,
+// falling back to an input.txt checked in next to the day. Either way it's handed over as a file rather than as
+// "cached", so the cache directory in `config` is the one that counts.
+pub fn check_part(entry: &SolutionEntry, part: u8, answers: &Answers, config: &Config) -> Outcome {
+    let Some(expected) = answers.get(entry.year, entry.day, part) else {
+        return Outcome::Skipped(Skip::NoAnswer);
+    };
+
+    let Some(path) = [config.cache_path(entry.year, entry.day), entry.dir.join("input.txt")]
+        .into_iter()
+        .find(|path| path.is_file())
+    else {
+        return Outcome::Skipped(Skip::NoInput);
+    };
+
+    check_run(entry, part, InputSource::File(path), expected)
+}
+
+// Runs one part against one of the day's example files. Only files on disk count: an example missing from there would
+// otherwise be fetched from the puzzle page.
+pub fn check_example(entry: &SolutionEntry, part: u8, name: &str, answers: &Answers) -> Outcome {
+    let Some(expected) = answers.get_example(entry.year, entry.day, name, part) else {
+        return Outcome::Skipped(Skip::NoAnswer);
+    };
+
+    let filename = format!("example{}.txt", name);
+    if ![entry.dir.join(&filename), entry.dir.join("src/bin").join(&filename)].iter().any(|path| path.is_file()) {
+        return Outcome::Skipped(Skip::NoInput);
+    }
+
+    check_run(entry, part, InputSource::Example(name.to_string()), expected)
+}
+
+fn check_run(entry: &SolutionEntry, part: u8, source: InputSource, expected: &str) -> Outcome {
+    let mut args = RunArgs::default();
+    args.source = source;
+
+    match run_part(entry, part, &args) {
+        Ok(outcome) if outcome.answer == expected => Outcome::Passed,
+        Ok(outcome) => Outcome::Failed { expected: expected.to_string(), actual: outcome.answer },
+        Err(e) => Outcome::Errored(e),
+    }
+}
+
+// Checks every part of every registered day, in order, each against its puzzle input and then any examples with a
+// recorded answer. Unlike `aoc run`, a failure doesn't stop the rest.
+pub fn check_all(registry: &Registry, answers: &Answers, config: &Config) -> Vec<CheckRow> {
+    let mut rows = Vec::new();
+
+    for entry in &registry.solutions {
+        for part in entry.parts.iter().map(|p| p.part) {
+            let row = |example: Option<&str>, outcome| CheckRow {
+                year: entry.year,
+                day: entry.day,
+                part,
+                example: example.map(String::from),
+                outcome,
+            };
+
+            rows.push(row(None, check_part(entry, part, answers, config)));
+
+            for name in answers.examples(entry.year, entry.day, part) {
+                rows.push(row(Some(name), check_example(entry, part, name, answers)));
+            }
+        }
+    }
+
+    rows
+}
+
+pub fn format_check_report(rows: &[CheckRow]) -> String {
+    let mut table = String::new();
+
+    for row in rows {
+        let _ = writeln!(table, "{:<24} {}", row.label(), row.outcome.describe());
+    }
+
+    let count = |f: fn(&Outcome) -> bool| rows.iter().filter(|r| f(&r.outcome)).count();
+    let _ = writeln!(
+        table,
+        "{} passed, {} failed, {} skipped",
+        count(|o| matches!(o, Outcome::Passed)),
+        count(Outcome::is_failure),
+        count(|o| matches!(o, Outcome::Skipped(_))),
+    );
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_numbers_and_strings() -> Result<(), AocError> {
+        let answers = Answers::from_toml("[2022.day01]\npart1 = 24000\npart2 = \" 45000 \"\n\n[2022.05]\npart1 = \"CMZ\"\n")?;
+
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(2022, 1, 1), Some("24000"));
+        assert_eq!(answers.get(2022, 1, 2), Some("45000"));
+        assert_eq!(answers.get(2022, 5, 1), Some("CMZ"));
+        assert_eq!(answers.get(2022, 5, 2), None);
+        Ok(())
+    }
+
+    #[test]
+    fn reads_example_tables() -> Result<(), AocError> {
+        let answers = Answers::from_toml("[2022.day01]\npart1 = 24000\n\n[2022.day01.example1_1]\npart2 = 45000\n\n[2022.day06]\nexample = { part1 = 7 }\n")?;
+
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get(2022, 1, 2), None);
+        assert_eq!(answers.get_example(2022, 1, "1_1", 2), Some("45000"));
+        assert_eq!(answers.get_example(2022, 6, "", 1), Some("7"));
+        assert_eq!(answers.examples(2022, 1, 2).collect::<Vec<_>>(), vec!["1_1"]);
+        assert_eq!(answers.examples(2022, 1, 1).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(Answers::from_toml("[2022.day01]\npart3 = 1\n").is_err());
+        assert!(Answers::from_toml("[twenty.day01]\npart1 = 1\n").is_err());
+        assert!(Answers::from_toml("[2022.day01]\npart1 = 1.5\n").is_err());
+        assert!(matches!(Answers::from_toml("[2022.day01]\npart1 = \n"), Err(AocError::Parse { line: 2, .. })));
+        assert!(Answers::from_toml("[2022.day01]\nexample = 1\n").is_err());
+        assert!(Answers::from_toml("[2022.day01.example]\npart3 = 1\n").is_err());
+    }
+
+    #[test]
+    fn missing_answers_and_inputs_are_skipped() -> Result<(), AocError> {
+        let registry = Registry::discover(&Registry::default_root())?;
+        let entry = registry.find(2022, 1).expect("2022 day 1 should be registered");
+
+        let cache_dir = std::env::temp_dir().join(format!("aoc_helpers_answers_skip_{}", std::process::id()));
+        let config = Config { cache_dir, ..Config::from_env() };
+
+        let mut answers = Answers::default();
+        assert!(matches!(check_part(entry, 1, &answers, &config), Outcome::Skipped(Skip::NoAnswer)));
+
+        answers.insert(2022, 1, 1, "24000");
+        assert!(matches!(check_part(entry, 1, &answers, &config), Outcome::Skipped(Skip::NoInput)));
+
+        // There's no example9.txt, and the puzzle page isn't fetched instead
+        assert!(matches!(check_example(entry, 1, "9", &answers), Outcome::Skipped(Skip::NoAnswer)));
+        answers.insert_example(2022, 1, "9", 1, "24000");
+        assert!(matches!(check_example(entry, 1, "9", &answers), Outcome::Skipped(Skip::NoInput)));
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{ Deserialize, Serialize };
+
+use crate::error::AocError;
+use crate::input::{ read_from_file_as_string, write_file };
+use crate::solution::{ solve, Solution };
+use crate::source::RunArgs;
+
+
+// Min, median and max of a set of timings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        match sorted.len() {
+            0 => Stats::default(),
+            n => Stats { min: sorted[0], median: sorted[n / 2], max: sorted[n - 1] },
+        }
+    }
+}
+
+// The timings for one part over a number of runs, split by phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl BenchResult {
+    pub fn total_median(&self) -> Duration {
+        self.parse.median + self.solve.median
+    }
+}
+
+// Parses and solves one part `runs` times. Every run has to come up with the same answer, since a benchmark of a
+// solution that can't make up its mind isn't worth much.
+pub fn bench<S: Solution>(solution: &S, input: &str, part: u8, runs: usize) -> Result<(String, BenchResult), AocError> {
+    let mut answer: Option<String> = None;
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve_times = Vec::with_capacity(runs);
+
+    for _ in 0..runs.max(1) {
+        let (this, timings) = solve(solution, input, part)?;
+
+        if let Some(previous) = &answer {
+            if *previous != this {
+                return Err(AocError::Solution {
+                    year: S::YEAR,
+                    day: S::DAY,
+                    part,
+                    message: format!("answer changed between runs ({} then {})", previous, this),
+                });
+            }
+        }
+
+        answer = Some(this);
+        parse.push(timings.parse);
+        solve_times.push(timings.solve);
+    }
+
+    let result = BenchResult {
+        runs: parse.len(),
+        parse: Stats::from_samples(&parse),
+        solve: Stats::from_samples(&solve_times),
+    };
+
+    Ok((answer.unwrap_or_default(), result))
+}
+
+// How many runs --bench asked for, if it was given. A bare --bench means 10.
+pub fn bench_runs(args: &RunArgs) -> Result<Option<usize>, AocError> {
+    let Some(i) = args.rest.iter().position(|a| a == "--bench") else {
+        return Ok(None);
+    };
+
+    match args.rest.get(i + 1) {
+        Some(n) if !n.starts_with('-') => {
+            n.parse().map(Some).map_err(|_| AocError::InvalidArgument(format!("--bench needs a number of runs, got {:?}", n)))
+        },
+        _ => Ok(Some(10)),
+    }
+}
+
+// Saved results keyed by "<year>/<day>/<part>", so a later run has something to compare against. It's plain JSON on
+// disk, sorted by key, so it diffs nicely when it's checked in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub results: BTreeMap<String, BenchResult>,
+}
+
+impl Baseline {
+    pub fn key(year: u16, day: u8, part: u8) -> String {
+        format!("{}/{:02}/{}", year, day, part)
+    }
+
+    // A missing file is just an empty baseline. A broken one is a parse error that says which file it was, since the
+    // baseline can live anywhere --baseline points.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        if !path.exists() {
+            return Ok(Baseline::default());
+        }
+
+        let contents = read_from_file_as_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| {
+            // serde_json tacks the position onto its message, but Parse already reports that
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or(&message);
+
+            AocError::parse(e.line(), e.column(), format!("{} isn't a valid baseline: {}", path.display(), message))
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let contents = serde_json::to_string_pretty(self).map_err(|e| AocError::io(path, e.into()))?;
+        write_file(path, &(contents + "\n"))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&BenchResult> {
+        self.results.get(&Baseline::key(year, day, part))
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, part: u8, result: BenchResult) {
+        self.results.insert(Baseline::key(year, day, part), result);
+    }
+}
+
+// How a result stacks up against its baseline, going by total median time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verdict {
+    New,
+    Faster(f64),
+    Unchanged(f64),
+    Slower(f64),
+}
+
+impl Verdict {
+
+    // `threshold` is the fractional change that counts as real, e.g. 0.1 for 10%. Anything inside it is noise.
+    pub fn compare(result: &BenchResult, baseline: Option<&BenchResult>, threshold: f64) -> Self {
+        let Some(baseline) = baseline else {
+            return Verdict::New;
+        };
+
+        let before = baseline.total_median().as_secs_f64();
+        let after = result.total_median().as_secs_f64();
+
+        if before == 0.0 {
+            return Verdict::New;
+        }
+
+        let change = after / before - 1.0;
+
+        if change > threshold {
+            Verdict::Slower(change)
+        } else if change < -threshold {
+            Verdict::Faster(change)
+        } else {
+            Verdict::Unchanged(change)
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Verdict::Slower(_))
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Verdict::New => "new".to_string(),
+            Verdict::Faster(change) => format!("{:+.1}% faster", change * 100.0),
+            Verdict::Unchanged(change) => format!("{:+.1}%", change * 100.0),
+            Verdict::Slower(change) => format!("{:+.1}% REGRESSION", change * 100.0),
+        }
+    }
+}
+
+// One line of the report table
+#[derive(Debug, Clone)]
+pub struct ReportRow {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub result: BenchResult,
+    pub verdict: Verdict,
+}
+
+pub fn format_report(rows: &[ReportRow]) -> String {
+    let mut table = String::new();
+
+    let _ = writeln!(
+        table,
+        "{:<12} {:>5}  {:>30}  {:>30}  change",
+        "puzzle", "runs", "parse (min / median / max)", "solve (min / median / max)"
+    );
+
+    for row in rows {
+        let _ = writeln!(
+            table,
+            "{:<12} {:>5}  {:>30}  {:>30}  {}",
+            format!("{} {:02}/{}", row.year, row.day, row.part),
+            row.result.runs,
+            format_stats(&row.result.parse),
+            format_stats(&row.result.solve),
+            row.verdict.describe(),
+        );
+    }
+
+    let regressions = rows.iter().filter(|r| r.verdict.is_regression()).count();
+    if regressions > 0 {
+        let _ = writeln!(table, "{} regression(s) against the baseline", regressions);
+    }
+
+    table
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!("{:.2?} / {:.2?} / {:.2?}", stats.min, stats.median, stats.max)
+}
+
+// Durations are stored as whole nanoseconds rather than serde's default {secs, nanos} pair
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{ Deserialize, Deserializer, Serializer };
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_nanos(u64::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn result(parse: u64, solve: u64) -> BenchResult {
+        let stats = |n| Stats { min: ms(n), median: ms(n), max: ms(n) };
+        BenchResult { runs: 1, parse: stats(parse), solve: stats(solve) }
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(9), ms(3), ms(7)]);
+        assert_eq!(stats, Stats { min: ms(1), median: ms(5), max: ms(9) });
+        assert_eq!(Stats::from_samples(&[]), Stats::default());
+    }
+
+    #[test]
+    fn verdicts() {
+        let baseline = result(10, 90);
+
+        assert_eq!(Verdict::compare(&result(10, 90), None, 0.1), Verdict::New);
+        assert!(matches!(Verdict::compare(&result(10, 95), Some(&baseline), 0.1), Verdict::Unchanged(_)));
+        assert!(matches!(Verdict::compare(&result(10, 40), Some(&baseline), 0.1), Verdict::Faster(_)));
+        assert!(Verdict::compare(&result(10, 150), Some(&baseline), 0.1).is_regression());
+    }
+
+    #[test]
+    fn baseline_round_trips() -> Result<(), Box<dyn std::error::Error>> {
+        let mut baseline = Baseline::default();
+        baseline.insert(2025, 10, 2, result(3, 14_000));
+
+        let json = serde_json::to_string(&baseline)?;
+        assert!(json.contains("\"2025/10/2\""));
+        assert!(json.contains("\"median\":14000000000"));
+
+        let loaded: Baseline = serde_json::from_str(&json)?;
+        assert_eq!(loaded.get(2025, 10, 2), Some(&result(3, 14_000)));
+        Ok(())
+    }
+
+    #[test]
+    fn broken_baseline_names_the_file() -> Result<(), Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir().join(format!("aoc_helpers_baseline_{}.json", std::process::id()));
+        write_file(&path, "{\"results\": {\n  \"2025/10/2\": 3\n}")?;
+
+        let error = Baseline::load(&path).unwrap_err();
+        std::fs::remove_file(&path)?;
+
+        match error {
+            AocError::Parse { line, message, .. } => {
+                assert_eq!(line, 2);
+                assert!(message.starts_with(&format!("{} isn't a valid baseline: invalid type", path.display())), "{}", message);
+                assert!(!message.contains(" at line "), "{}", message);
+            },
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parses_bench_runs() -> Result<(), AocError> {
+        let args = |s: &str| RunArgs::parse(s.split_whitespace().map(String::from));
+
+        assert_eq!(bench_runs(&args("--example")?)?, None);
+        assert_eq!(bench_runs(&args("--bench 25")?)?, Some(25));
+        assert_eq!(bench_runs(&args("--bench --example")?)?, Some(10));
+        assert!(matches!(bench_runs(&args("--bench many")?), Err(AocError::InvalidArgument(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc_helpers::bench::{ format_report, Baseline, ReportRow, Verdict };
+use aoc_helpers::registry::{ Registry, SolutionEntry };
+use aoc_helpers::runner::{ bench_part, run_part };
+use aoc_helpers::{ AocError, RunArgs };
+
+const USAGE: &str = "\
+usage: aoc run <year> <day> [--part N] [--input <source> | --example [name]] [--set key=value]...
+       aoc run <year> --all [--part N] [...]
+       aoc bench <year> (<day> | --all) [--part N] [--runs N] [--threshold PCT] [--baseline <path>] [--save] [...]
+       aoc list [year]";
+
+// Where `aoc bench` keeps its baseline unless told otherwise, relative to the repo root
+const BASELINE: &str = "benchmarks.json";
+
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+// The command is the first thing left over once RunArgs has taken the options it knows about. from_env also falls
+// back to $AOC_INPUT when there's no --input or --example, which then gets forwarded to each solution.
+fn run() -> Result<(), AocError> {
+    let mut args = RunArgs::from_env()?;
+    let command = (!args.rest.is_empty()).then(|| args.rest.remove(0));
+    let registry = Registry::discover(&Registry::default_root())?;
+
+    match command.as_deref() {
+        Some("run") => run_command(&registry, &args),
+        Some("bench") => bench_command(&registry, &args),
+        Some("list") => list_command(&registry, &args),
+        _ => Err(usage()),
+    }
+}
+
+// Runs one day, or every day of a year with --all. Parts run in order and a failure stops the lot.
+fn run_command(registry: &Registry, args: &RunArgs) -> Result<(), AocError> {
+    let selection = Selection::parse(registry, &args.rest, |_, _| Err(usage()))?;
+
+    for (entry, part) in selection.parts() {
+        let outcome = run_part(entry, part, args)?;
+        println!("{} day {:02} part {}: {:<20} ({:?})", outcome.year, outcome.day, outcome.part, outcome.answer, outcome.elapsed);
+    }
+
+    Ok(())
+}
+
+// Benchmarks one day or a whole year and reports each part against the saved baseline. --save writes the new numbers
+// back as the baseline for next time.
+fn bench_command(registry: &Registry, args: &RunArgs) -> Result<(), AocError> {
+    let mut runs = 10;
+    let mut threshold = 10.0;
+    let mut path = registry.root.join(BASELINE);
+    let mut save = false;
+
+    let selection = Selection::parse(registry, &args.rest, |arg, rest| {
+        match arg {
+            "--runs" | "-n" => runs = number(rest.next())?,
+            "--threshold" => threshold = number(rest.next())?,
+            "--baseline" => path = rest.next().map(PathBuf::from).ok_or_else(usage)?,
+            "--save" => save = true,
+            _ => return Err(usage()),
+        }
+        Ok(())
+    })?;
+
+    let mut baseline = Baseline::load(&path)?;
+    let mut rows = Vec::new();
+
+    for (entry, part) in selection.parts() {
+        let (_, result) = bench_part(entry, part, args, runs)?;
+        let verdict = Verdict::compare(&result, baseline.get(entry.year, entry.day, part), threshold / 100.0);
+
+        rows.push(ReportRow { year: entry.year, day: entry.day, part, result, verdict });
+    }
+
+    print!("{}", format_report(&rows));
+
+    if save {
+        for row in &rows {
+            baseline.insert(row.year, row.day, row.part, row.result);
+        }
+
+        baseline.save(&path)?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    Ok(())
+}
+
+// The <year> (<day> | --all) [--part N] that run and bench share. Anything else is handed to `other` along with the
+// remaining args, so a command can take its own flags.
+struct Selection<'a> {
+    days: Vec<&'a SolutionEntry>,
+    part: Option<u8>,
+}
+
+impl<'a> Selection<'a> {
+    fn parse<F>(registry: &'a Registry, args: &[String], mut other: F) -> Result<Self, AocError>
+    where
+        F: FnMut(&str, &mut std::slice::Iter<'_, String>) -> Result<(), AocError>,
+    {
+        let mut year = None;
+        let mut day = None;
+        let mut part = None;
+        let mut all = false;
+
+        let mut rest = args.iter();
+        while let Some(arg) = rest.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--part" | "-p" => part = Some(number::<u8>(rest.next())?),
+                _ if arg.starts_with('-') => other(arg, &mut rest)?,
+                _ if year.is_none() => year = Some(number::<u16>(Some(arg))?),
+                _ if day.is_none() => day = Some(number::<u8>(Some(arg))?),
+                _ => return Err(usage()),
+            }
+        }
+
+        let year = year.ok_or_else(usage)?;
+
+        let days = match (day, all) {
+            (Some(day), false) => vec![registry.find(year, day).ok_or(AocError::NoSolution { year, day, part: None })?],
+            (None, true) => registry.year(year).collect(),
+            _ => return Err(usage()),
+        };
+
+        Ok(Selection { days, part })
+    }
+
+    // Every (day, part) pair selected, in order
+    fn parts(&self) -> Vec<(&'a SolutionEntry, u8)> {
+        self.days
+            .iter()
+            .flat_map(|entry| {
+                let parts: Vec<u8> = match self.part {
+                    Some(part) => vec![part],
+                    None => entry.parts.iter().map(|p| p.part).collect(),
+                };
+                parts.into_iter().map(move |part| (*entry, part))
+            })
+            .collect()
+    }
+}
+
+fn list_command(registry: &Registry, args: &RunArgs) -> Result<(), AocError> {
+    let year = match args.rest.first() {
+        Some(year) => Some(number::<u16>(Some(year))?),
+        None => None,
+    };
+
+    for entry in registry.solutions.iter().filter(|s| year.is_none_or(|y| s.year == y)) {
+        let parts: Vec<String> = entry.parts.iter().map(|p| p.part.to_string()).collect();
+        println!("{} day {:02}  parts {}", entry.year, entry.day, parts.join(", "));
+    }
+
+    Ok(())
+}
+
+fn number<T: std::str::FromStr>(arg: Option<&String>) -> Result<T, AocError> {
+    arg.and_then(|a| a.parse().ok()).ok_or_else(usage)
+}
+
+fn usage() -> AocError {
+    AocError::Usage(USAGE.to_string())
+}
@@ -0,0 +1,375 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{ BitAnd, BitOr, BitXor, BitXorAssign };
+
+use crate::error::AocError;
+
+
+// Sets of small numbers packed into bits, for the light-toggling and mask puzzles where a state is "which of these
+// are on". The plain unsigned integers are the fast path and cover anything up to 128 items; BitSet grows to fit
+// whatever it's given. Either way, setting a bit that doesn't fit is a panic rather than a silently lost light, and the
+// parsers check first so a line that's too wide is a parse error instead.
+pub trait Bits:
+    Clone + Default + Eq + Hash + Debug + BitXor<Output = Self> + BitAnd<Output = Self> + BitOr<Output = Self>
+{
+    fn get(&self, i: usize) -> bool;
+    fn set(&mut self, i: usize);
+    fn unset(&mut self, i: usize);
+    fn toggle(&mut self, i: usize);
+
+    // How many bits are set
+    fn count(&self) -> usize;
+
+    // The indices of the set bits, lowest first
+    fn ones(&self) -> impl Iterator<Item = usize> + '_;
+
+    fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    // How many bits fit, or None if there's no limit
+    fn capacity() -> Option<usize> {
+        None
+    }
+
+    fn from_indices(indices: impl IntoIterator<Item = usize>) -> Self {
+        let mut bits = Self::default();
+        for i in indices {
+            bits.set(i);
+        }
+        bits
+    }
+
+    // "[#..#.]": bit i is set when light i is a '#'. As with parse_indices, the brackets are optional.
+    fn parse_lights(s: &str) -> Result<Self, AocError> {
+        let (lights, offset) = unbracket(s);
+        let mut bits = Self::default();
+
+        for (i, ch) in lights.chars().enumerate() {
+            match ch {
+                '#' => bits.set(fits::<Self>(i, offset + i + 1)?),
+                '.' => {},
+                _ => return Err(AocError::parse(1, offset + i + 1, format!("expected only '#' and '.', got {:?}", s))),
+            }
+        }
+
+        Ok(bits)
+    }
+
+    // "(1,3,5)": the listed bits are set. Any kind of brackets (or none) can go around the list.
+    fn parse_indices(s: &str) -> Result<Self, AocError> {
+        let (list, offset) = unbracket(s);
+        if list.trim().is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut column = offset + 1;
+        let mut bits = Self::default();
+
+        for n in list.split(',') {
+            let start = column + n.len() - n.trim_start().len();
+            let i = n
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| AocError::parse(1, start, format!("expected a list of bit numbers, got {:?}", s)))?;
+
+            bits.set(fits::<Self>(i, start)?);
+            column += n.len() + 1;
+        }
+
+        Ok(bits)
+    }
+
+    // Every subset of the set bits, the empty set included. There are 2^count of them.
+    fn subsets(&self) -> impl Iterator<Item = Self> + '_ {
+        let ones: Vec<usize> = self.ones().collect();
+        assert!(ones.len() < 64, "{} bits have too many subsets to go through", ones.len());
+
+        // Counting up through a u64 picks out each combination of those bits once
+        (0..1u64 << ones.len()).map(move |pick| {
+            Self::from_indices(pick.ones().map(|i| ones[i]))
+        })
+    }
+
+    // Every subset with exactly k of the set bits
+    fn subsets_of_size(&self, k: usize) -> impl Iterator<Item = Self> + '_ {
+        self.subsets().filter(move |subset| subset.count() == k)
+    }
+}
+
+macro_rules! fixed_bits {
+    ($($t:ty),*) => {
+        $(
+            impl Bits for $t {
+                fn get(&self, i: usize) -> bool {
+                    i < <$t>::BITS as usize && self >> i & 1 == 1
+                }
+
+                fn set(&mut self, i: usize) {
+                    *self |= bit::<$t>(i);
+                }
+
+                fn unset(&mut self, i: usize) {
+                    *self &= !bit::<$t>(i);
+                }
+
+                fn toggle(&mut self, i: usize) {
+                    *self ^= bit::<$t>(i);
+                }
+
+                fn count(&self) -> usize {
+                    self.count_ones() as usize
+                }
+
+                fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+                    let mut rest = *self;
+                    std::iter::from_fn(move || {
+                        (rest != 0).then(|| {
+                            let i = rest.trailing_zeros() as usize;
+                            rest &= rest - 1;
+                            i
+                        })
+                    })
+                }
+
+                fn is_empty(&self) -> bool {
+                    *self == 0
+                }
+
+                fn capacity() -> Option<usize> {
+                    Some(<$t>::BITS as usize)
+                }
+
+                // Walks the submasks in increasing order without building anything: setting every bit outside the
+                // mask and adding one carries straight through to the next submask
+                fn subsets(&self) -> impl Iterator<Item = Self> + '_ {
+                    let mask = *self;
+                    let mut next = Some(0);
+
+                    std::iter::from_fn(move || {
+                        let current = next?;
+                        let following = (current | !mask).wrapping_add(1) & mask;
+                        next = (following != 0).then_some(following);
+                        Some(current)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+fixed_bits!(u8, u16, u32, u64, u128);
+
+// Strips whitespace and any brackets from around a list, returning what's left and how far into s it starts
+fn unbracket(s: &str) -> (&str, usize) {
+    let open = s.trim_start().trim_start_matches(['(', '[', '{']);
+    (open.trim_end().trim_end_matches([')', ']', '}']), s.len() - open.len())
+}
+
+// Passes bit i through if it fits in B, and otherwise says where in the line it came from
+fn fits<B: Bits>(i: usize, column: usize) -> Result<usize, AocError> {
+    match B::capacity() {
+        Some(width) if i >= width => {
+            Err(AocError::parse(1, column, format!("bit {} doesn't fit in {} bits; BitSet can hold any number", i, width)))
+        },
+        _ => Ok(i),
+    }
+}
+
+fn bit<T: TryFrom<u128> + Default + Debug>(i: usize) -> T {
+    let width = std::mem::size_of::<T>() * 8;
+    assert!(i < width, "bit {} doesn't fit in {} bits; BitSet can hold any number", i, width);
+    T::try_from(1u128 << i).unwrap_or_default()
+}
+
+// A bitset that grows as bits are set, for when there could be more than 128 things to keep track of
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet {
+
+    // Never ends in a zero word, so equal sets always compare and hash the same however they were built
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn trim(&mut self) {
+        while self.words.last() == Some(&0) {
+            self.words.pop();
+        }
+    }
+
+    fn word_mut(&mut self, i: usize) -> &mut u64 {
+        if self.words.len() <= i / 64 {
+            self.words.resize(i / 64 + 1, 0);
+        }
+        &mut self.words[i / 64]
+    }
+
+    fn zip_with(&self, other: &BitSet, op: impl Fn(u64, u64) -> u64) -> BitSet {
+        let len = self.words.len().max(other.words.len());
+        let word = |words: &[u64], i: usize| words.get(i).copied().unwrap_or(0);
+
+        let mut result = BitSet { words: (0..len).map(|i| op(word(&self.words, i), word(&other.words, i))).collect() };
+        result.trim();
+        result
+    }
+}
+
+impl Bits for BitSet {
+    fn get(&self, i: usize) -> bool {
+        self.words.get(i / 64).is_some_and(|word| word >> (i % 64) & 1 == 1)
+    }
+
+    fn set(&mut self, i: usize) {
+        *self.word_mut(i) |= 1 << (i % 64);
+    }
+
+    fn unset(&mut self, i: usize) {
+        if let Some(word) = self.words.get_mut(i / 64) {
+            *word &= !(1 << (i % 64));
+            self.trim();
+        }
+    }
+
+    fn toggle(&mut self, i: usize) {
+        *self.word_mut(i) ^= 1 << (i % 64);
+        self.trim();
+    }
+
+    fn count(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, word)| word.ones().map(move |i| w * 64 + i))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl BitXor for BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, other: BitSet) -> BitSet {
+        &self ^ &other
+    }
+}
+
+impl BitXor for &BitSet {
+    type Output = BitSet;
+
+    fn bitxor(self, other: &BitSet) -> BitSet {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+}
+
+impl BitXorAssign<&BitSet> for BitSet {
+    fn bitxor_assign(&mut self, other: &BitSet) {
+        *self = &*self ^ other;
+    }
+}
+
+impl BitAnd for BitSet {
+    type Output = BitSet;
+
+    fn bitand(self, other: BitSet) -> BitSet {
+        self.zip_with(&other, |a, b| a & b)
+    }
+}
+
+impl BitOr for BitSet {
+    type Output = BitSet;
+
+    fn bitor(self, other: BitSet) -> BitSet {
+        self.zip_with(&other, |a, b| a | b)
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        Self::from_indices(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_lights_and_buttons() -> Result<(), AocError> {
+        assert_eq!(u16::parse_lights(".##.")?, 0b0110);
+        assert_eq!(u16::parse_lights("[.##.]")?, 0b0110);
+        assert_eq!(u16::parse_indices("(1,3)")?, 0b1010);
+        assert_eq!(u64::parse_indices("{0, 5}")?, 0b100001);
+        assert_eq!(u8::parse_indices("()")?, 0);
+        assert!(u8::parse_lights(".#x").is_err());
+        assert!(u8::parse_indices("(1,a)").is_err());
+
+        // Past what a u128 holds, which is where BitSet comes in
+        let wide = BitSet::parse_indices("(3,200)")?;
+        assert_eq!(wide.ones().collect::<Vec<_>>(), vec![3, 200]);
+        assert_eq!(wide, BitSet::parse_lights(&format!("...#{}#", ".".repeat(196)))?);
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_width_refuses_wide_bits() -> Result<(), AocError> {
+        assert!(matches!(u16::parse_indices("(3, 16)"), Err(AocError::Parse { column: 5, .. })));
+        assert_eq!(u16::parse_indices(" (3, 15)")?, 0b1000_0000_0000_1000);
+        assert!(u64::parse_indices("(64)").is_err());
+
+        // The 65th light is the one that doesn't fit, one column in past the bracket
+        let wide = format!("[{}]", "#".repeat(65));
+        assert!(matches!(u64::parse_lights(&wide), Err(AocError::Parse { column: 66, .. })));
+        assert_eq!(BitSet::parse_lights(&wide)?.count(), 65);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn setting_a_bit_that_doesnt_fit_panics() {
+        let mut bits = 0u16;
+        bits.set(16);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = BitSet::from_indices([1, 70, 130]);
+        let b = BitSet::from_indices([70, 131]);
+
+        assert_eq!((&a ^ &b).ones().collect::<Vec<_>>(), vec![1, 130, 131]);
+        assert_eq!((a.clone() & b.clone()).ones().collect::<Vec<_>>(), vec![70]);
+        assert_eq!((a.clone() | b).count(), 4);
+
+        // Toggling the high bits back off gives the same set as never having had them
+        let mut c = a.clone();
+        c.toggle(300);
+        c.toggle(300);
+        c.unset(130);
+        c.unset(70);
+        assert_eq!(c, BitSet::from_indices([1]));
+        assert!(!c.get(500));
+
+        let mut d = 0u128;
+        d.set(127);
+        d.toggle(3);
+        assert_eq!(d.ones().collect::<Vec<_>>(), vec![3, 127]);
+    }
+
+    #[test]
+    fn subsets() {
+        assert_eq!(0b1010u8.subsets().collect::<Vec<_>>(), vec![0b0000, 0b0010, 0b1000, 0b1010]);
+        assert_eq!(u64::MAX.subsets_of_size(1).take(3).collect::<Vec<_>>(), vec![1, 2, 4]);
+        assert_eq!(0u32.subsets().count(), 1);
+        assert_eq!(0b1111_0000u16.subsets_of_size(2).count(), 6);
+
+        let wide = BitSet::from_indices([0, 100, 200]);
+        assert_eq!(wide.subsets().count(), 8);
+        assert_eq!(wide.subsets_of_size(2).filter(|s| s.get(100)).count(), 2);
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+
+// Where a repeating sequence of states starts repeating, and how long the loop is. Step 0 is the initial state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+
+    // The earliest step that's in the same state as step `n`
+    pub fn index_at(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+// Finds the loop in `initial, step(initial), step(step(initial)), ...` by remembering every state seen. Fast, but
+// it holds on to one copy of each state until the first repeat. The sequence has to repeat eventually.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = initial;
+
+    for i in 0.. {
+        if let Some(&start) = seen.get(&state) {
+            return Cycle { start, length: i - start };
+        }
+
+        let next = step(&state);
+        seen.insert(state, i);
+        state = next;
+    }
+
+    unreachable!()
+}
+
+// The state after `n` steps, skipping over whole loops once one turns up. This is the "run it a billion times"
+// helper: it simulates until a state repeats (or it gets to `n` first) and jumps straight to the answer.
+pub fn state_at<S, F>(initial: S, n: usize, mut step: F) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut history: Vec<S> = Vec::new();
+    let mut state = initial;
+
+    for i in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle { start, length: i - start };
+            return history.swap_remove(cycle.index_at(n));
+        }
+
+        let next = step(&state);
+        seen.insert(state.clone(), i);
+        history.push(state);
+        state = next;
+    }
+
+    state
+}
+
+// Brent's algorithm: the same answer as find_cycle, but only ever holding two states and needing nothing more than
+// equality. Worth it when the states are big or awkward to hash. It does step the sequence a few more times.
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    brent_with(initial, |s| Some(step(s))).expect("an endless sequence always repeats")
+}
+
+// The state after `n` steps using Brent's algorithm to find the loop. Finding it can take a few times as many
+// steps as the loop's start and length, so the search gives up after `n` steps and just simulates if n is small.
+pub fn state_at_brent<S, F>(initial: S, n: usize, mut step: F) -> S
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut budget = n;
+    let cycle = brent_with(initial.clone(), |state| {
+        budget = budget.checked_sub(1)?;
+        Some(step(state))
+    });
+
+    let mut state = initial;
+    for _ in 0..cycle.map_or(n, |cycle| cycle.index_at(n)) {
+        state = step(&state);
+    }
+
+    state
+}
+
+// Whether a walk that can end (`step` returning None, like a guard leaving the map) instead goes round in circles
+// forever. Uses Brent's algorithm, so there's no visited set to build.
+pub fn ends_in_cycle<S, F>(initial: S, step: F) -> bool
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    brent_with(initial, step).is_some()
+}
+
+fn brent_with<S, F>(initial: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    // Find the loop length: the hare runs ahead in doubling stretches, and the tortoise teleports to it at the start
+    // of each stretch, until the hare laps it
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    // Find where it starts: with the hare a loop's length ahead, they meet at the first repeated state
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3 -> 10 -> 101 -> 2 -> 5 -> ... falls into a loop after a few steps
+    fn lcg(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    fn brute_force(n: usize) -> u32 {
+        (0..n).fold(3, |x, _| lcg(&x))
+    }
+
+    #[test]
+    fn both_finders_agree() {
+        let hashed = find_cycle(3, lcg);
+        assert_eq!(brent(3, lcg), hashed);
+
+        // Step start + length really is back at step start
+        assert_eq!(brute_force(hashed.start), brute_force(hashed.start + hashed.length));
+        assert_ne!(brute_force(hashed.start), brute_force(hashed.start + hashed.length - 1));
+    }
+
+    #[test]
+    fn skips_to_step_n() {
+        for n in [0, 1, 5, 17, 100, 1000] {
+            assert_eq!(state_at(3, n, lcg), brute_force(n));
+            assert_eq!(state_at_brent(3, n, lcg), brute_force(n));
+        }
+
+        assert_eq!(state_at(3, 1_000_000_000, lcg), state_at_brent(3, 1_000_000_000, lcg));
+    }
+
+    #[test]
+    fn brent_simulates_when_n_comes_before_the_loop() {
+        // This only repeats when the counter wraps, so looking for the loop first would take forever
+        assert_eq!(state_at_brent(0u64, 1000, |x| x + 1), 1000);
+        assert_eq!(state_at_brent(0u64, 0, |x| x + 1), 0);
+    }
+
+    #[test]
+    fn spots_walks_that_never_end() {
+        assert!(ends_in_cycle(0u32, |&x| Some((x + 1) % 7)));
+        assert!(!ends_in_cycle(0u32, |&x| (x < 100).then_some(x + 1)));
+    }
+}
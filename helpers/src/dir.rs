@@ -0,0 +1,220 @@
+use crate::error::AocError;
+use crate::grid::Grid;
+
+
+// The four cardinal directions on a grid where y grows downward, so N is (0, -1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+
+    // Clockwise from north, which is what turning relies on
+    pub const ALL: [Dir4; 4] = [Dir4::N, Dir4::E, Dir4::S, Dir4::W];
+
+    pub fn offset(self) -> (i32, i32) {
+        match self {
+            Dir4::N => (0, -1),
+            Dir4::E => (1, 0),
+            Dir4::S => (0, 1),
+            Dir4::W => (-1, 0),
+        }
+    }
+
+    // One step from `pos`, wherever that lands
+    pub fn step(self, (x, y): (i32, i32)) -> (i32, i32) {
+        let (dx, dy) = self.offset();
+        (x + dx, y + dy)
+    }
+
+    // One step from `pos`, or None if that walks off the grid
+    pub fn step_on<T>(self, grid: &Grid<T>, pos: (i32, i32)) -> Option<(i32, i32)> {
+        let (x, y) = self.step(pos);
+        grid.in_bounds(x, y).then_some((x, y))
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    // The four positions next to `pos`, on the grid or not
+    pub fn neighbours(pos: (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+        Dir4::ALL.into_iter().map(move |dir| dir.step(pos))
+    }
+
+    // The positions next to `pos` that are on the grid
+    pub fn neighbours_on<T>(grid: &Grid<T>, pos: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        Dir4::ALL.into_iter().filter_map(move |dir| dir.step_on(grid, pos))
+    }
+
+    // The arrow a puzzle would draw for this direction
+    pub fn arrow(self) -> char {
+        match self {
+            Dir4::N => '^',
+            Dir4::E => '>',
+            Dir4::S => 'v',
+            Dir4::W => '<',
+        }
+    }
+}
+
+// Reads the arrows (^ v < >) that mark a facing on a map, and the letters (U D L R) that instructions use
+impl TryFrom<char> for Dir4 {
+    type Error = AocError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            '^' | 'U' => Ok(Dir4::N),
+            '>' | 'R' => Ok(Dir4::E),
+            'v' | 'D' => Ok(Dir4::S),
+            '<' | 'L' => Ok(Dir4::W),
+            _ => Err(AocError::InvalidArgument(format!("{:?} isn't a direction", ch))),
+        }
+    }
+}
+
+// The four cardinal directions plus the diagonals between them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+
+    // Clockwise from north, so turning is an eighth of a turn (45 degrees) at a time
+    pub const ALL: [Dir8; 8] = [Dir8::N, Dir8::NE, Dir8::E, Dir8::SE, Dir8::S, Dir8::SW, Dir8::W, Dir8::NW];
+
+    pub fn offset(self) -> (i32, i32) {
+        match self {
+            Dir8::N => (0, -1),
+            Dir8::NE => (1, -1),
+            Dir8::E => (1, 0),
+            Dir8::SE => (1, 1),
+            Dir8::S => (0, 1),
+            Dir8::SW => (-1, 1),
+            Dir8::W => (-1, 0),
+            Dir8::NW => (-1, -1),
+        }
+    }
+
+    pub fn step(self, (x, y): (i32, i32)) -> (i32, i32) {
+        let (dx, dy) = self.offset();
+        (x + dx, y + dy)
+    }
+
+    pub fn step_on<T>(self, grid: &Grid<T>, pos: (i32, i32)) -> Option<(i32, i32)> {
+        let (x, y) = self.step(pos);
+        grid.in_bounds(x, y).then_some((x, y))
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn neighbours(pos: (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+        Dir8::ALL.into_iter().map(move |dir| dir.step(pos))
+    }
+
+    pub fn neighbours_on<T>(grid: &Grid<T>, pos: (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        Dir8::ALL.into_iter().filter_map(move |dir| dir.step_on(grid, pos))
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::N => Dir8::N,
+            Dir4::E => Dir8::E,
+            Dir4::S => Dir8::S,
+            Dir4::W => Dir8::W,
+        }
+    }
+}
+
+impl TryFrom<char> for Dir8 {
+    type Error = AocError;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        Dir4::try_from(ch).map(Dir8::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        assert_eq!(Dir4::N.turn_right(), Dir4::E);
+        assert_eq!(Dir4::N.turn_left(), Dir4::W);
+        assert_eq!(Dir4::E.opposite(), Dir4::W);
+
+        assert_eq!(Dir8::N.turn_right(), Dir8::NE);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::SE.opposite(), Dir8::NW);
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().opposite(), dir);
+            let (dx, dy) = dir.offset();
+            assert_eq!(dir.opposite().offset(), (-dx, -dy));
+        }
+    }
+
+    #[test]
+    fn parses_arrows_and_letters() -> Result<(), AocError> {
+        assert_eq!(Dir4::try_from('^')?, Dir4::N);
+        assert_eq!(Dir4::try_from('v')?, Dir4::S);
+        assert_eq!(Dir4::try_from('L')?, Dir4::W);
+        assert_eq!(Dir8::try_from('R')?, Dir8::E);
+        assert!(matches!(Dir4::try_from('x'), Err(AocError::InvalidArgument(_))));
+
+        for dir in Dir4::ALL {
+            assert_eq!(Dir4::try_from(dir.arrow())?, dir);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn steps_stay_on_the_grid() {
+        let grid = Grid::new(3, 3, '.');
+
+        assert_eq!(Dir4::N.step_on(&grid, (0, 0)), None);
+        assert_eq!(Dir4::S.step_on(&grid, (0, 0)), Some((0, 1)));
+        assert_eq!(Dir4::neighbours_on(&grid, (0, 0)).count(), 2);
+        assert_eq!(Dir8::neighbours_on(&grid, (0, 0)).count(), 3);
+        assert_eq!(Dir8::neighbours_on(&grid, (1, 1)).count(), 8);
+        assert_eq!(Dir4::neighbours((0, 0)).count(), 4);
+    }
+}
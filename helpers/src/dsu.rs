@@ -0,0 +1,141 @@
+// A disjoint-set (union-find) over the indices 0..n, with union by size and path compression. On top of the usual
+// find/union it keeps the live component count and each component's size, so "how many groups are there" and "how
+// big are the biggest ones" don't need a pass over every element.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+
+    // n elements, each in a component of its own
+    pub fn new(n: usize) -> Self {
+        DisjointSet { parent: (0..n).collect(), size: vec![1; n], components: n }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    // The representative of x's component. Everything on the way up is pointed at its grandparent (path halving),
+    // which keeps the trees close to flat.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    // find without the compression, for when only a shared reference is to hand
+    pub fn root(&self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            x = self.parent[x];
+        }
+        x
+    }
+
+    // Joins the components holding a and b. Returns false if they were already one component.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // How many separate components there are right now
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    // The size of the component holding x
+    pub fn size_of(&self, x: usize) -> usize {
+        self.size[self.root(x)]
+    }
+
+    // The size of every component, in no particular order
+    pub fn sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(|&x| self.parent[x] == x).map(|x| self.size[x])
+    }
+
+    // The sizes of the k largest components, largest first
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.sizes().collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+
+    // The members of each component, each list in ascending order
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+
+        for x in 0..self.len() {
+            let root = self.root(x);
+            if index[root] == usize::MAX {
+                index[root] = groups.len();
+                groups.push(Vec::with_capacity(self.size[root]));
+            }
+            groups[index[root]].push(x);
+        }
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_components() {
+        let mut set = DisjointSet::new(8);
+        assert_eq!(set.component_count(), 8);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(1, 2));
+        assert!(set.union(5, 6));
+        assert!(!set.union(2, 0));
+
+        assert!(set.same(0, 2));
+        assert!(!set.same(0, 5));
+        assert_eq!(set.component_count(), 5);
+        assert_eq!(set.size_of(2), 3);
+        assert_eq!(set.size_of(7), 1);
+        assert_eq!(set.largest(3), vec![3, 2, 1]);
+        assert_eq!(set.largest(3).iter().product::<usize>(), 6);
+        assert_eq!(set.sizes().sum::<usize>(), 8);
+    }
+
+    #[test]
+    fn lists_members() {
+        let mut set = DisjointSet::new(6);
+        set.union(4, 1);
+        set.union(3, 5);
+        set.union(1, 0);
+
+        let mut components = set.components();
+        components.sort();
+        assert_eq!(components, vec![vec![0, 1, 4], vec![2], vec![3, 5]]);
+    }
+}
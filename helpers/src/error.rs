@@ -0,0 +1,116 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+
+// Everything that can go wrong in the helpers. Each case carries enough context (the day, the URL, the path, the
+// line and column) to tell what actually failed, and callers can match on it instead of string-sniffing.
+#[derive(Debug)]
+pub enum AocError {
+
+    // AOC_TOKEN (or Config::token) wasn't set, so there's no session to send
+    MissingToken,
+
+    // A cache miss in offline mode
+    Offline { year: u16, day: u8, path: PathBuf },
+
+    // AoC answered 404, which is what it does for days that haven't unlocked yet
+    NotUnlocked { year: u16, day: u8 },
+
+    // AoC answered 400, which is what it does for a missing or expired session cookie
+    BadSession,
+
+    // Any other non-2xx response
+    Http { status: u16, url: String },
+
+    // The request never got a response (DNS, TLS, connection refused...)
+    Network(reqwest::Error),
+
+    // Reading or writing a file failed
+    Io { path: PathBuf, source: io::Error },
+
+    // Input didn't have the expected shape. Line and column are 1-based.
+    Parse { line: usize, column: usize, message: String },
+
+    // The command line didn't make sense; the message is the usage text to show
+    Usage(String),
+
+    // A flag, parameter or other value handed to a helper that it can't work with
+    InvalidArgument(String),
+
+    // The server replied with something we couldn't make sense of
+    UnexpectedResponse(String),
+
+    // The ledger ruled out a guess before it was sent
+    GuessRefused(String),
+
+    // There's no solution registered for that day (or part)
+    NoSolution { year: u16, day: u8, part: Option<u8> },
+
+    // A solution failed to build or exited with an error
+    Solution { year: u16, day: u8, part: u8, message: String },
+}
+
+impl AocError {
+    pub fn io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        AocError::Io { path: path.into(), source }
+    }
+
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse { line, column, message: message.into() }
+    }
+
+    // Maps a non-2xx status from a puzzle endpoint to the most specific error we can give
+    pub fn from_status(status: u16, url: &str, year: u16, day: u8) -> Self {
+        match status {
+            404 => AocError::NotUnlocked { year, day },
+            400 => AocError::BadSession,
+            _ => AocError::Http { status, url: url.to_string() },
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingToken => write!(f, "AOC_TOKEN is not set"),
+            AocError::Offline { year, day, path } => {
+                write!(f, "No cached input for {} day {} at {} (offline mode)", year, day, path.display())
+            },
+            AocError::NotUnlocked { year, day } => write!(f, "{} day {} hasn't unlocked yet (404)", year, day),
+            AocError::BadSession => write!(f, "AoC rejected the session cookie (400); is AOC_TOKEN current?"),
+            AocError::Http { status, url } => write!(f, "Request to {} failed: {}", url, status),
+            AocError::Network(e) => write!(f, "Network error: {}", e),
+            AocError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            AocError::Parse { line, column, message } => write!(f, "Parse error at line {}, column {}: {}", line, column, message),
+            AocError::Usage(usage) => write!(f, "{}", usage),
+            AocError::InvalidArgument(msg) => write!(f, "Invalid argument: {}", msg),
+            AocError::UnexpectedResponse(msg) => write!(f, "Unexpected response: {}", msg),
+            AocError::GuessRefused(msg) => write!(f, "Guess refused: {}", msg),
+            AocError::NoSolution { year, day, part: None } => write!(f, "No solution for {} day {}", year, day),
+            AocError::NoSolution { year, day, part: Some(part) } => {
+                write!(f, "No solution for {} day {} part {}", year, day, part)
+            },
+            AocError::Solution { year, day, part, message } => {
+                write!(f, "{} day {} part {} failed: {}", year, day, part, message)
+            },
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Network(e) => Some(e),
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(e: reqwest::Error) -> Self {
+        AocError::Network(e)
+    }
+}
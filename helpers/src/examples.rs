@@ -0,0 +1,236 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{ Duration, SystemTime };
+
+use crate::error::AocError;
+use crate::input::{ write_file, Config };
+
+// A cached page without part 2 is fetched again only if it's at least this old, so asking for a part 2 example
+// before part 2 is unlocked doesn't send a request on every run
+const PART2_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+// One <pre><code> block from the puzzle description. Numbers start at 1 and run across both parts in page order.
+// The answer is the emphasized value (<code><em>...</em></code>) that follows the block in the text, if there is one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub number: usize,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+}
+
+// Gets every example from a day's puzzle description
+pub fn get_examples(year: u16, day: u8) -> Result<Vec<Example>, AocError> {
+    get_examples_with_config(year, day, &Config::from_env())
+}
+
+// Same as get_examples, but with explicit settings instead of reading them from the environment. A cached page is
+// used as it is, even if it only has part 1 on it.
+pub fn get_examples_with_config(year: u16, day: u8, config: &Config) -> Result<Vec<Example>, AocError> {
+    Ok(extract_examples(&get_puzzle_page(year, day, 1, config)?))
+}
+
+// Finds one example by number. If it isn't on the cached page and that page predates part 2, the page is fetched
+// again in case part 2 (and the example) has shown up since.
+pub fn get_example_with_config(year: u16, day: u8, number: usize, config: &Config) -> Result<Option<Example>, AocError> {
+    let find = |examples: Vec<Example>| examples.into_iter().find(|e| e.number == number);
+
+    if let Some(example) = find(get_examples_with_config(year, day, config)?) {
+        return Ok(Some(example));
+    }
+
+    Ok(find(extract_examples(&get_puzzle_page(year, day, 2, config)?)))
+}
+
+// Gets the puzzle description HTML, cached like the input. Part 2 only shows up on the page once part 1 is solved,
+// so when `part` is 2 and the cached page doesn't have it yet, the page is fetched again (when we're online, and no
+// more than once every PART2_REFRESH). Asking for part 1 never refreshes anything.
+pub fn get_puzzle_page(year: u16, day: u8, part: u8, config: &Config) -> Result<String, AocError> {
+    let path = config.page_cache_path(year, day);
+
+    if path.is_file() {
+        let page = fs::read_to_string(&path).map_err(|e| AocError::io(&path, e))?;
+
+        let complete = page.matches("<article").count() >= part as usize;
+        let fresh = fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .is_ok_and(|modified| SystemTime::now().duration_since(modified).unwrap_or_default() < PART2_REFRESH);
+
+        if config.offline || complete || fresh {
+            return Ok(page);
+        }
+
+    } else if config.offline {
+        return Err(AocError::Offline { year, day, path });
+    }
+
+    let url = config.puzzle_url(year, day);
+    let mut request = config.client.get(&url);
+
+    // The description is public, but part 2 only comes back for a logged-in session
+    if let Some(token) = &config.token {
+        request = request.header("Cookie", format!("session={}", token));
+    }
+
+    let response = request.send()?;
+
+    if !response.status().is_success() {
+        return Err(AocError::from_status(response.status().as_u16(), &url, year, day));
+    }
+
+    let page = response.text()?;
+    write_file(&path, &page)?;
+
+    Ok(page)
+}
+
+// Pulls every <pre><code> block out of the puzzle description HTML, along with the answer that goes with it
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+
+    for (i, article) in articles(html).into_iter().enumerate() {
+        let part = (i + 1) as u8;
+        let blocks = code_blocks(article);
+
+        for (j, &(start, end)) in blocks.iter().enumerate() {
+
+            // The answer for a block is the last emphasized code span between it and the next block
+            let next = blocks.get(j + 1).map_or(article.len(), |&(s, _)| s);
+            let answer = emphasized_code(&article[end..next]).pop();
+
+            examples.push(Example {
+                number: examples.len() + 1,
+                part,
+                input: unescape(&strip_tags(&article[start..end])),
+                answer,
+            });
+        }
+    }
+
+    examples
+}
+
+// The contents of each <article class="day-desc"> on the page, in order (one per part)
+fn articles(html: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+
+    while let Some(open) = rest.find("<article") {
+        let Some(body) = rest[open..].find('>').map(|i| open + i + 1) else { break };
+        let close = rest[body..].find("</article>").map_or(rest.len(), |i| body + i);
+
+        found.push(&rest[body..close]);
+        rest = &rest[close..];
+    }
+
+    // Saved fixtures are sometimes just a fragment of the page
+    if found.is_empty() {
+        found.push(html);
+    }
+
+    found
+}
+
+// Byte ranges of the contents of every <pre><code> block in an article
+fn code_blocks(article: &str) -> Vec<(usize, usize)> {
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+
+    while let Some(i) = article[pos..].find("<pre><code>") {
+        let start = pos + i + "<pre><code>".len();
+        let Some(len) = article[start..].find("</code></pre>") else { break };
+
+        blocks.push((start, start + len));
+        pos = start + len;
+    }
+
+    blocks
+}
+
+// Every <code><em>...</em></code> (or <em><code>...</code></em>) value in a stretch of text
+fn emphasized_code(text: &str) -> Vec<String> {
+    let mut values = Vec::new();
+
+    for (open, close) in [("<code><em>", "</em></code>"), ("<em><code>", "</code></em>")] {
+        let mut pos = 0;
+
+        while let Some(i) = text[pos..].find(open) {
+            let start = pos + i + open.len();
+            let Some(len) = text[start..].find(close) else { break };
+
+            values.push((start, unescape(&strip_tags(&text[start..start + len]))));
+            pos = start + len;
+        }
+    }
+
+    values.sort_by_key(|(pos, _)| *pos);
+    values.into_iter().map(|(_, value)| value).collect()
+}
+
+// Drops any tags inside a block (AoC sometimes emphasizes part of an example)
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_tag = false;
+
+    for ch in s.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(ch),
+            _ => {},
+        }
+    }
+
+    out
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+impl Example {
+
+    // The example split into lines, the same shape read_from_file_as_lines gives
+    pub fn lines(&self) -> Vec<String> {
+        self.input.lines().map(String::from).collect()
+    }
+}
+
+impl Config {
+
+    // URL of a given day's puzzle description
+    pub fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url.trim_end_matches('/'), year, day)
+    }
+
+    // Path of the cached puzzle description, e.g. <cache_dir>/2025/day05.html
+    pub fn page_cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir.join(year.to_string()).join(format!("day{:02}.html", day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_inner_tags_and_entities() {
+        let html = "<pre><code>a -&gt; b\n<em>c</em> &amp; d\n</code></pre><p>Total: <code><em>7</em></code>.</p>";
+        let examples = extract_examples(html);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].input, "a -> b\nc & d\n");
+        assert_eq!(examples[0].answer.as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn block_without_emphasized_value_has_no_answer() {
+        let html = "<pre><code>1\n2\n</code></pre><p>Nothing to see here.</p>";
+
+        assert_eq!(extract_examples(html)[0].answer, None);
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::{ HashMap, HashSet };
+use std::hash::Hash;
+
+
+// Unweighted graph searches over any node type. As with the shortest-path helpers, the graph is never built up front:
+// `neighbours` is asked for a node's edges as the search reaches it, so a grid position, a device name or a whole
+// puzzle state all work as nodes.
+
+// Breadth-first distances (in steps) from `start` to every node reachable from it
+pub fn bfs<S, N, I>(start: S, neighbours: N) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    bfs_order(start, neighbours).into_iter().collect()
+}
+
+// Every node reachable from `start`, `start` included
+pub fn reachable<S, N, I>(start: S, neighbours: N) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    bfs_order(start, neighbours).into_iter().map(|(node, _)| node).collect()
+}
+
+// The distinct nodes satisfying `is_target` that can be reached from `start`, in the order the search found them
+// (so nearest first)
+pub fn reachable_targets<S, N, I, T>(start: S, neighbours: N, mut is_target: T) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    T: FnMut(&S) -> bool,
+{
+    bfs_order(start, neighbours).into_iter().map(|(node, _)| node).filter(|node| is_target(node)).collect()
+}
+
+// Every reachable node with its distance, in the order the search reached them
+fn bfs_order<S, N, I>(start: S, mut neighbours: N) -> Vec<(S, usize)>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen: HashSet<S> = HashSet::from([start.clone()]);
+    let mut order: Vec<(S, usize)> = vec![(start, 0)];
+    let mut i = 0;
+
+    // The order list doubles as the queue: everything past `i` is still waiting to be expanded
+    while i < order.len() {
+        let (node, steps) = order[i].clone();
+        i += 1;
+
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                order.push((next, steps + 1));
+            }
+        }
+    }
+
+    order
+}
+
+// Depth-first search from `start` that stops at the first node `found` accepts, and returns it. Each node is visited
+// at most once.
+pub fn dfs<S, N, I, F>(start: S, mut neighbours: N, mut found: F) -> Option<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> bool,
+{
+    let mut seen: HashSet<S> = HashSet::new();
+    let mut stack: Vec<S> = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+
+        if found(&node) {
+            return Some(node);
+        }
+
+        stack.extend(neighbours(&node).into_iter().filter(|next| !seen.contains(next)));
+    }
+
+    None
+}
+
+// The number of distinct paths from `start` to any node `is_target` accepts. A path ends at the first target it
+// reaches. The graph has to be acyclic, which it is in the puzzles that ask for this (a cycle would mean infinitely
+// many paths). Counts are memoized per node, so anything a puzzle needs to track along the way (like "have we
+// passed through dac yet?") belongs in the node itself.
+pub fn count_paths<S, N, I, T>(start: S, mut neighbours: N, mut is_target: T) -> u64
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    T: FnMut(&S) -> bool,
+{
+    let mut memo: HashMap<S, u64> = HashMap::new();
+    paths_from(&start, &mut neighbours, &mut is_target, &mut memo)
+}
+
+fn paths_from<S, N, I, T>(node: &S, neighbours: &mut N, is_target: &mut T, memo: &mut HashMap<S, u64>) -> u64
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    T: FnMut(&S) -> bool,
+{
+    if is_target(node) {
+        return 1;
+    }
+
+    if let Some(&count) = memo.get(node) {
+        return count;
+    }
+
+    let mut count = 0;
+    for next in neighbours(node) {
+        count += paths_from(&next, neighbours, is_target, memo);
+    }
+
+    memo.insert(node.clone(), count);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A diamond: a -> b, a -> c, b -> d, c -> d, d -> e
+    fn edges(node: &&'static str) -> Vec<&'static str> {
+        match *node {
+            "a" => vec!["b", "c"],
+            "b" | "c" => vec!["d"],
+            "d" => vec!["e"],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn distances_and_reachability() {
+        let dist = bfs("a", edges);
+
+        assert_eq!(dist["a"], 0);
+        assert_eq!(dist["d"], 2);
+        assert_eq!(dist["e"], 3);
+        assert_eq!(reachable("b", edges), HashSet::from(["b", "d", "e"]));
+        assert_eq!(reachable_targets("a", edges, |n| *n != "a" && *n != "d"), vec!["b", "c", "e"]);
+    }
+
+    #[test]
+    fn dfs_stops_early() {
+        let mut visited = 0;
+        let found = dfs("a", |n| { visited += 1; edges(n) }, |n| *n == "c");
+
+        assert_eq!(found, Some("c"));
+        assert!(visited < 5);
+        assert_eq!(dfs("a", edges, |n| *n == "z"), None);
+    }
+
+    #[test]
+    fn counts_paths() {
+        assert_eq!(count_paths("a", edges, |n| *n == "e"), 2);
+        assert_eq!(count_paths("a", edges, |n| *n == "z"), 0);
+
+        // A ladder 20 rungs high has 2^20 paths, which only finishes quickly with the memo
+        let ladder = |&(rung, _): &(u32, u8)| {
+            if rung == 20 { vec![] } else { vec![(rung + 1, 0), (rung + 1, 1)] }
+        };
+        assert_eq!(count_paths((0, 0), ladder, |&(rung, _)| rung == 20), 1 << 20);
+    }
+}
@@ -0,0 +1,252 @@
+use std::fmt;
+use std::ops::{ Index, IndexMut };
+
+use crate::error::AocError;
+
+
+// A dense, row-major 2D grid. Coordinates are (x, y) with x across and y down, and they're signed so that stepping
+// off an edge gives None from `get` instead of an underflow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { cells: vec![fill; width * height], width, height }
+    }
+
+    // Builds a grid from rows, which all have to be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, AocError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(AocError::parse(y + 1, 1, format!("row is {} wide, expected {}", row.len(), width)));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid { cells, width, height })
+    }
+
+    // Parses one cell per character, one row per line, through the given mapping. Trailing blank lines are ignored;
+    // a character the mapping rejects is reported with its line and column.
+    pub fn parse_with<F, E>(input: &str, mut cell: F) -> Result<Self, AocError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut lines: Vec<&str> = input.lines().collect();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+
+        let rows = lines
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, ch)| cell(ch).map_err(|_| AocError::parse(y + 1, x + 1, format!("unexpected character {:?}", ch))))
+                    .collect::<Result<Vec<T>, AocError>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, AocError>>()?;
+
+        Grid::from_rows(rows)
+    }
+
+    // Parses through the cell type's own TryFrom<char>. Grid<char> works too, since every type converts from itself.
+    pub fn parse(input: &str) -> Result<Self, AocError>
+    where
+        T: TryFrom<char>,
+    {
+        Grid::parse_with(input, T::try_from)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn offset(&self, x: i32, y: i32) -> Option<usize> {
+        self.in_bounds(x, y).then(|| y as usize * self.width + x as usize)
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.offset(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.offset(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // Replaces the cell at (x, y) and hands back what was there, or None (and no change) if it's off the grid
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> Option<T> {
+        self.get_mut(x, y).map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() panics on 0, and a zero-width grid has no rows worth yielding anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    // Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.cells.iter().enumerate().map(move |(i, cell)| (((i % self.width) as i32, (i / self.width) as i32), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.iter().map(|(pos, _)| pos)
+    }
+
+    // The first position (in reading order) holding `value`, e.g. the '^' or 'S' a puzzle starts from
+    pub fn find(&self, value: &T) -> Option<(i32, i32)>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (i32, i32)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter().filter(move |(_, cell)| *cell == value).map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+}
+
+// Unchecked (x, y) indexing for when the position is known to be on the grid
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is off a {}x{} grid", x, y, self.width, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is off a {}x{} grid", x, y, self.width, self.height);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// Prints the grid back out the way it was parsed, as long as each cell displays as the character it came from
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Cell { Open, Wall }
+
+    impl TryFrom<char> for Cell {
+        type Error = ();
+
+        fn try_from(ch: char) -> Result<Self, Self::Error> {
+            match ch {
+                '.' => Ok(Cell::Open),
+                '#' => Ok(Cell::Wall),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[test]
+    fn parses_and_round_trips() -> Result<(), AocError> {
+        let grid = Grid::<char>::parse(MAP)?;
+
+        assert_eq!((grid.width(), grid.height()), (10, 10));
+        assert_eq!(grid.find(&'^'), Some((4, 6)));
+        assert_eq!(grid.to_string(), MAP);
+        Ok(())
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        assert!(matches!(Grid::<Cell>::parse(MAP), Err(AocError::Parse { line: 7, column: 5, .. })));
+        assert!(matches!(Grid::<char>::parse("...\n..\n"), Err(AocError::Parse { line: 2, .. })));
+    }
+
+    #[test]
+    fn signed_access_is_bounds_checked() -> Result<(), AocError> {
+        let mut grid = Grid::<Cell>::parse(&MAP.replace('^', "."))?;
+
+        assert_eq!(grid.get(4, 0), Some(&Cell::Wall));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(0, 10), None);
+
+        assert_eq!(grid.set(4, 0, Cell::Open), Some(Cell::Wall));
+        assert_eq!(grid.set(10, 0, Cell::Wall), None);
+        assert_eq!(grid[(4, 0)], Cell::Open);
+        Ok(())
+    }
+
+    #[test]
+    fn rows_and_columns() -> Result<(), AocError> {
+        let grid = Grid::parse_with("123\n456\n", |ch| ch.to_digit(10).ok_or(()))?;
+
+        assert_eq!(grid.rows().map(|row| row.iter().sum::<u32>()).collect::<Vec<_>>(), vec![6, 15]);
+        assert_eq!(grid.columns().map(|col| col.sum::<u32>()).collect::<Vec<_>>(), vec![5, 7, 9]);
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.find_all(&5).collect::<Vec<_>>(), vec![(1, 1)]);
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use std::fs::{self, File};
+use std::io::{ prelude::*, BufReader };
+use std::env;
+use std::path::{ Path, PathBuf };
+use reqwest::blocking::Client;
+
+use crate::error::AocError;
+
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Settings for fetching puzzle input. Inputs are cached on disk under cache_dir, keyed by year and day, so only
+// the first run for a given day ever touches the network. With offline set, a cache miss is an error rather than
+// a request, which keeps benchmark loops and CI runs from hammering the site. The base URL and client can be
+// swapped out so the fetcher can be pointed at a local stand-in server.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub cache_dir: PathBuf,
+    pub offline: bool,
+    pub base_url: String,
+    pub token: Option<String>,
+    pub client: Client,
+}
+
+impl Config {
+
+    // Builds a config from the environment. AOC_CACHE_DIR overrides the cache location (which otherwise defaults to
+    // $XDG_CACHE_HOME/aoc or ~/.cache/aoc), setting AOC_OFFLINE to anything other than "0" or "false" turns on
+    // offline mode, AOC_BASE_URL overrides https://adventofcode.com, and AOC_TOKEN is the session cookie.
+    pub fn from_env() -> Self {
+        let cache_dir = match env::var_os("AOC_CACHE_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => default_cache_dir(),
+        };
+
+        let offline = env::var("AOC_OFFLINE")
+            .map(|v| !matches!(v.trim().to_ascii_lowercase().as_str(), "" | "0" | "false"))
+            .unwrap_or(false);
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let token = env::var("AOC_TOKEN").ok();
+
+        Self { cache_dir, offline, base_url, token, client: Client::new() }
+    }
+
+    // Path of the cached input for a given day, e.g. <cache_dir>/2025/day05.txt
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+    }
+
+    // URL of a given day's input on the configured server
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url.trim_end_matches('/'), year, day)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return PathBuf::from(dir).join("aoc");
+    }
+
+    match env::var_os("HOME") {
+        Some(home) => PathBuf::from(home).join(".cache").join("aoc"),
+        None => PathBuf::from(".aoc_cache"),
+    }
+}
+
+// Gets the puzzle input, reading it from the on-disk cache if we've already downloaded it and fetching it from
+// AoC's website (then caching it) otherwise
+pub fn get_puzzle_input_as_string(year: u16, day: u8) -> Result<String, AocError> {
+    get_puzzle_input_with_config(year, day, &Config::from_env())
+}
+
+// Same as get_puzzle_input_as_string, but with explicit settings instead of reading them from the environment
+pub fn get_puzzle_input_with_config(year: u16, day: u8, config: &Config) -> Result<String, AocError> {
+    let path = config.cache_path(year, day);
+
+    if path.is_file() {
+        return read_from_file_as_string(&path);
+    }
+
+    if config.offline {
+        return Err(AocError::Offline { year, day, path });
+    }
+
+    let input = fetch_puzzle_input(year, day, config)?;
+    write_file(&path, &input)?;
+
+    Ok(input)
+}
+
+// Writes a file, creating its parent directories first
+pub(crate) fn write_file(path: &Path, contents: &str) -> Result<(), AocError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| AocError::io(parent, e))?;
+    }
+
+    fs::write(path, contents).map_err(|e| AocError::io(path, e))
+}
+
+// Downloads the puzzle input from the configured server, bypassing the cache
+fn fetch_puzzle_input(year: u16, day: u8, config: &Config) -> Result<String, AocError> {
+    let session_token = config.token.as_deref().ok_or(AocError::MissingToken)?;
+    let url = config.input_url(year, day);
+
+    let response = config.client
+        .get(&url)
+        .header("Cookie", format!("session={}", session_token))
+        .send()?;
+
+    if !response.status().is_success() {
+        return Err(AocError::from_status(response.status().as_u16(), &url, year, day));
+    }
+
+    Ok(response.text()?)
+}
+
+// Gets the puzzle input and returns it as a vector of strings, split by line
+pub fn get_puzzle_input_as_lines(year: u16, day:u8) -> Result<Vec<String>, AocError> {
+    get_puzzle_input_as_lines_with_config(year, day, &Config::from_env())
+}
+
+// Same as get_puzzle_input_as_lines, but with explicit settings instead of reading them from the environment
+pub fn get_puzzle_input_as_lines_with_config(year: u16, day: u8, config: &Config) -> Result<Vec<String>, AocError> {
+    let raw_input = get_puzzle_input_with_config(year, day, config)?;
+
+    Ok(raw_input.lines().map(String::from).collect())
+}
+
+// Reads input from a file and returns it as a single string
+pub fn read_from_file_as_string(filename: impl AsRef<Path>) -> Result<String, AocError> {
+    let path = filename.as_ref();
+
+    fs::read_to_string(path).map_err(|e| AocError::io(path, e))
+}
+
+// Reads input from a file and returns it as a vector of strings, split by line
+pub fn read_from_file_as_lines(filename: impl AsRef<Path>) -> Result<Vec<String>, AocError> {
+    let path = filename.as_ref();
+
+    let file = File::open(path).map_err(|e| AocError::io(path, e))?;
+    let reader = BufReader::new(file);
+
+    let mut lines = Vec::new();
+
+    for line in reader.lines() {
+        lines.push(line.map_err(|e| AocError::io(path, e))?);
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_config(name: &str, offline: bool) -> Config {
+        let cache_dir = env::temp_dir().join(format!("aoc_helpers_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+
+        Config { cache_dir, offline, ..Config::from_env() }
+    }
+
+    #[test]
+    fn cached_input_is_read_without_network() -> Result<(), Box<dyn std::error::Error>> {
+        let config = temp_config("cache_hit", true);
+        let path = config.cache_path(2025, 5);
+
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, "3-5\n10-14\n")?;
+
+        let input = get_puzzle_input_with_config(2025, 5, &config)?;
+        assert_eq!(input, "3-5\n10-14\n");
+
+        fs::remove_dir_all(&config.cache_dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn offline_cache_miss_fails_fast() {
+        let config = temp_config("cache_miss", true);
+
+        let err = get_puzzle_input_with_config(2025, 5, &config).unwrap_err();
+        assert!(matches!(err, AocError::Offline { year: 2025, day: 5, .. }));
+    }
+
+    #[test]
+    fn cache_path_is_keyed_by_year_and_day() {
+        let config = Config { cache_dir: PathBuf::from("/cache"), ..Config::from_env() };
+
+        assert_eq!(config.cache_path(2022, 8), PathBuf::from("/cache/2022/day08.txt"));
+    }
+
+    #[test]
+    fn missing_file_reports_its_path() {
+        let err = read_from_file_as_lines("does_not_exist.txt").unwrap_err();
+
+        match err {
+            AocError::Io { path, .. } => assert_eq!(path, PathBuf::from("does_not_exist.txt")),
+            other => panic!("Expected an I/O error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn input_url_uses_configured_base() {
+        let config = Config { base_url: "http://127.0.0.1:8080/".to_string(), ..Config::from_env() };
+
+        assert_eq!(config.input_url(2024, 10), "http://127.0.0.1:8080/2024/day/10/input");
+    }
+}
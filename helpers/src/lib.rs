@@ -1,14 +1,50 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+// Shared helpers for every year's solutions. Each year workspace (2022 through 2025) depends on this crate
+// by path, so a fix here lands everywhere at once.
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub mod answers;
+pub mod bench;
+pub mod bitset;
+pub mod cycle;
+pub mod dir;
+pub mod dsu;
+pub mod error;
+pub mod examples;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod linear;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod point;
+pub mod polygon;
+pub mod range;
+pub mod registry;
+pub mod runner;
+pub mod search;
+pub mod solution;
+pub mod source;
+pub mod spatial;
+pub mod submit;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
-}
+pub use bitset::*;
+pub use cycle::*;
+pub use dir::*;
+pub use dsu::*;
+pub use error::AocError;
+pub use examples::*;
+pub use graph::*;
+pub use grid::*;
+pub use input::*;
+pub use linear::*;
+pub use math::*;
+pub use memo::*;
+pub use parse::*;
+pub use point::*;
+pub use polygon::*;
+pub use range::*;
+pub use search::*;
+pub use solution::*;
+pub use source::*;
+pub use spatial::*;
+pub use submit::*;
@@ -0,0 +1,451 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{ Add, Div, Mul, Neg, Sub };
+
+use crate::math::{ gcd, lcm };
+
+
+// Exact linear algebra for the "which combination of these adds up to that" puzzles. Everything is done over
+// fractions, so elimination never rounds, and the answer can be checked for being a whole number at the end.
+
+// A fraction in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    // Panics on a zero denominator
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "{}/0 isn't a number", num);
+
+        let g = gcd(num, den);
+        let sign = den.signum();
+        Rational { num: sign * num / g, den: sign * den / g }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    // The value, if it's a whole number
+    pub fn to_integer(&self) -> Option<i128> {
+        self.is_integer().then_some(self.num)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational { num: n as i128, den: 1 }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.den + other.num * self.den, self.den * other.den)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.num, self.den * other.den)
+    }
+}
+
+// Panics when dividing by zero
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        Rational::new(self.num * other.den, self.den * other.num)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational { num: -self.num, den: self.den }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+// Puts an augmented matrix (each row is the coefficients followed by the right-hand side) into reduced row echelon
+// form in place, and returns the pivot column of each row that still has one. Those rows come first, in order.
+pub fn row_reduce(rows: &mut [Vec<Rational>]) -> Vec<usize> {
+    let columns = rows.first().map_or(0, |row| row.len().saturating_sub(1));
+    let mut pivots = Vec::new();
+
+    for col in 0..columns {
+        let r = pivots.len();
+        let Some(found) = (r..rows.len()).find(|&i| !rows[i][col].is_zero()) else {
+            continue;
+        };
+        rows.swap(r, found);
+
+        let pivot = rows[r][col];
+        for value in rows[r].iter_mut() {
+            *value = *value / pivot;
+        }
+
+        for i in 0..rows.len() {
+            let factor = rows[i][col];
+            if i != r && !factor.is_zero() {
+                for j in 0..rows[i].len() {
+                    rows[i][j] = rows[i][j] - factor * rows[r][j];
+                }
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    pivots
+}
+
+// Every solution to A x = b, described by the reduced system: the pivot variables are fixed once the free ones are
+// chosen
+#[derive(Debug, Clone)]
+pub struct SolutionSpace {
+    variables: usize,
+    rows: Vec<Vec<Rational>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+}
+
+impl SolutionSpace {
+
+    // None if the equations contradict each other. `a` has one row per equation and one column per variable.
+    pub fn new(a: &[Vec<i64>], b: &[i64]) -> Option<Self> {
+        let variables = a.first().map_or(0, Vec::len);
+
+        let mut rows: Vec<Vec<Rational>> = a
+            .iter()
+            .zip(b)
+            .map(|(row, &rhs)| row.iter().chain([&rhs]).map(|&n| Rational::from(n)).collect())
+            .collect();
+
+        let pivots = row_reduce(&mut rows);
+
+        // Below the pivot rows everything on the left is zero, so anything but zero on the right is 0 = 1
+        if rows[pivots.len()..].iter().any(|row| !row[variables].is_zero()) {
+            return None;
+        }
+
+        rows.truncate(pivots.len());
+        let free = (0..variables).filter(|col| !pivots.contains(col)).collect();
+
+        Some(SolutionSpace { variables, rows, pivots, free })
+    }
+
+    // The variables that can take any value, with everything else following from them
+    pub fn free_variables(&self) -> &[usize] {
+        &self.free
+    }
+
+    pub fn is_unique(&self) -> bool {
+        self.free.is_empty()
+    }
+
+    // The full solution for the given values of the free variables (in free_variables order)
+    pub fn evaluate(&self, free_values: &[Rational]) -> Vec<Rational> {
+        let mut x = vec![Rational::ZERO; self.variables];
+
+        for (&col, &value) in self.free.iter().zip(free_values) {
+            x[col] = value;
+        }
+
+        for (row, &pivot) in self.rows.iter().zip(&self.pivots) {
+            x[pivot] = self.free.iter().fold(row[self.variables], |acc, &col| acc - row[col] * x[col]);
+        }
+
+        x
+    }
+
+    // The non-negative whole-number solution with the smallest total, with every variable at most its `upper`
+    // bound. The free variables are tried across their whole range, so this is only quick when there are few of
+    // them or the bounds are tight.
+    pub fn min_sum_nonnegative(&self, upper: &[i64]) -> Option<Vec<i64>> {
+        // Clear the fractions out of each row so the search can stay in integers: scale * x_pivot = rhs - sum of
+        // coeff * x_free
+        let rows: Vec<ScaledRow> = self
+            .rows
+            .iter()
+            .zip(&self.pivots)
+            .map(|(row, &pivot)| {
+                let scale = row.iter().fold(1, |acc, value| lcm(acc, value.den));
+                let scaled = |value: Rational| value.num * (scale / value.den);
+
+                ScaledRow {
+                    pivot,
+                    scale,
+                    rhs: scaled(row[self.variables]),
+                    coeffs: self.free.iter().map(|&col| scaled(row[col])).collect(),
+                }
+            })
+            .collect();
+
+        let mut search = Search::new(&rows, &self.free, upper);
+        search.assign(0);
+
+        search.best.map(|(_, free_values)| {
+            let mut x = vec![0; self.variables];
+            for (&col, &value) in self.free.iter().zip(&free_values) {
+                x[col] = value;
+            }
+            for row in &rows {
+                let taken: i128 = row.coeffs.iter().zip(&free_values).map(|(&c, &v)| c * v as i128).sum();
+                x[row.pivot] = ((row.rhs - taken) / row.scale) as i64;
+            }
+            x
+        })
+    }
+}
+
+struct ScaledRow {
+    pivot: usize,
+    scale: i128,
+    rhs: i128,
+    coeffs: Vec<i128>,
+}
+
+// A branch and bound over the free variables, one at a time. Before going any deeper it checks that every pivot
+// variable can still land in range whatever the rest of the free variables turn out to be, and that the total can
+// still beat the best found so far.
+struct Search<'a> {
+    rows: &'a [ScaledRow],
+    upper: Vec<i64>,
+    pivot_upper: Vec<i128>,
+
+    // For each row, the lowest and highest the free variables from k onwards could take off its right-hand side
+    reach: Vec<Vec<(i128, i128)>>,
+
+    // The total is tracked scaled up by `scale`, the lcm of the row scales, to keep it whole. Each free variable moves
+    // it by a fixed weight per step, and `slack[k]` is the most the free variables from k onwards could bring it down.
+    scale: i128,
+    slack: Vec<i128>,
+
+    // The free variables chosen so far, and each row's right-hand side with them taken off
+    values: Vec<i64>,
+    residuals: Vec<i128>,
+
+    best: Option<(i128, Vec<i64>)>,
+}
+
+impl<'a> Search<'a> {
+    fn new(rows: &'a [ScaledRow], free: &[usize], upper: &[i64]) -> Self {
+        let n = free.len();
+        let free_upper: Vec<i64> = free.iter().map(|&col| upper[col]).collect();
+
+        let reach = rows
+            .iter()
+            .map(|row| {
+                let mut reach = vec![(0, 0); n + 1];
+                for k in (0..n).rev() {
+                    let most = row.coeffs[k] * free_upper[k] as i128;
+                    reach[k] = (reach[k + 1].0 + most.min(0), reach[k + 1].1 + most.max(0));
+                }
+                reach
+            })
+            .collect();
+
+        let scale = rows.iter().fold(1, |acc, row| lcm(acc, row.scale));
+        let weights: Vec<i128> = (0..n)
+            .map(|k| scale - rows.iter().map(|row| row.coeffs[k] * (scale / row.scale)).sum::<i128>())
+            .collect();
+
+        let mut slack = vec![0; n + 1];
+        for k in (0..n).rev() {
+            slack[k] = slack[k + 1] + (weights[k] * free_upper[k] as i128).min(0);
+        }
+
+        Search {
+            rows,
+            pivot_upper: rows.iter().map(|row| upper[row.pivot] as i128 * row.scale).collect(),
+            upper: free_upper,
+            reach,
+            scale,
+            slack,
+            values: vec![0; n],
+            residuals: rows.iter().map(|row| row.rhs).collect(),
+            best: None,
+        }
+    }
+
+    fn assign(&mut self, k: usize) {
+        if !self.promising(k) {
+            return;
+        }
+
+        if k == self.values.len() {
+            self.check();
+            return;
+        }
+
+        for value in 0..=self.upper[k] {
+            self.values[k] = value;
+            self.assign(k + 1);
+
+            for (residual, row) in self.residuals.iter_mut().zip(self.rows) {
+                *residual -= row.coeffs[k];
+            }
+        }
+
+        // Put the residuals back the way they were before this variable was touched
+        let steps = self.upper[k] as i128 + 1;
+        for (residual, row) in self.residuals.iter_mut().zip(self.rows) {
+            *residual += row.coeffs[k] * steps;
+        }
+    }
+
+    // With the free variables before k fixed (and the rest still at zero in the residuals)
+    fn promising(&self, k: usize) -> bool {
+        let in_range = self.rows.iter().enumerate().all(|(r, _)| {
+            let (low, high) = self.reach[r][k];
+            self.residuals[r] - high <= self.pivot_upper[r] && self.residuals[r] - low >= 0
+        });
+
+        in_range && self.best.as_ref().is_none_or(|(best, _)| self.scaled_total(k) + self.slack[k] < *best)
+    }
+
+    // The scaled total with the free variables from k onwards at zero
+    fn scaled_total(&self, k: usize) -> i128 {
+        let free: i128 = self.values[..k].iter().map(|&v| v as i128).sum();
+        let pivots: i128 = self.rows.iter().zip(&self.residuals).map(|(row, &r)| r * (self.scale / row.scale)).sum();
+        free * self.scale + pivots
+    }
+
+    fn check(&mut self) {
+        if self.rows.iter().zip(&self.residuals).any(|(row, &residual)| residual % row.scale != 0) {
+            return;
+        }
+
+        let total = self.scaled_total(self.values.len());
+        if self.best.as_ref().is_none_or(|(best, _)| total < *best) {
+            self.best = Some((total, self.values.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(num: i128, den: i128) -> Rational {
+        Rational::new(num, den)
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(r(2, 4), r(1, 2));
+        assert_eq!(r(1, -3), r(-1, 3));
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(3, 4), r(-1, 4));
+        assert_eq!(r(2, 3) * r(3, 4), r(1, 2));
+        assert_eq!(r(1, 2) / r(1, 4), Rational::from(2));
+        assert!(r(-1, 2) < r(1, 3));
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(7, 2).to_string(), "7/2");
+    }
+
+    #[test]
+    fn unique_solutions() {
+        // x + y = 3, x - y = 1
+        let space = SolutionSpace::new(&[vec![1, 1], vec![1, -1]], &[3, 1]).unwrap();
+        assert!(space.is_unique());
+        assert_eq!(space.evaluate(&[]), vec![Rational::from(2), Rational::from(1)]);
+
+        // x + y = 1 and x + y = 2 can't both hold
+        assert!(SolutionSpace::new(&[vec![1, 1], vec![1, 1]], &[1, 2]).is_none());
+    }
+
+    #[test]
+    fn fewest_presses() {
+        // The first machine from 2025 day 10: buttons (3) (1,3) (2) (2,3) (0,2) (0,1), joltages {3,5,4,7}. Each row is
+        // a counter and each column a button that bumps it.
+        let a = vec![
+            vec![0, 0, 0, 0, 1, 1],
+            vec![0, 1, 0, 0, 0, 1],
+            vec![0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 1, 0, 0],
+        ];
+        let b = [3, 5, 4, 7];
+
+        let space = SolutionSpace::new(&a, &b).unwrap();
+        assert_eq!(space.free_variables().len(), 2);
+
+        let presses = space.min_sum_nonnegative(&[7, 5, 4, 4, 3, 3]).unwrap();
+        assert_eq!(presses.iter().sum::<i64>(), 10);
+
+        // And it really does hit every target
+        for (row, &target) in a.iter().zip(&b) {
+            assert_eq!(row.iter().zip(&presses).map(|(a, x)| a * x).sum::<i64>(), target);
+        }
+
+        // Tightening the bounds until there's no room left gives no answer rather than a wrong one
+        assert!(space.min_sum_nonnegative(&[0, 0, 0, 0, 0, 0]).is_none());
+    }
+
+    #[test]
+    fn needs_whole_numbers() {
+        // 2x = 3 has a solution, just not a whole one
+        let space = SolutionSpace::new(&[vec![2]], &[3]).unwrap();
+        assert_eq!(space.evaluate(&[]), vec![r(3, 2)]);
+        assert!(space.min_sum_nonnegative(&[10]).is_none());
+    }
+}
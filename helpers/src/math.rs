@@ -0,0 +1,225 @@
+use std::fmt::Debug;
+use std::ops::{ Add, Div, Mul, Neg, Rem, Sub };
+
+
+// The primitive integers the number theory helpers work over. The modular functions widen to i128/u128 for their
+// intermediate products, and fall back to multiplying step by step when even that would overflow, so any modulus
+// that fits in an i128 works.
+pub trait Integer:
+    Copy + Ord + Debug
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+fn abs<T: Integer>(x: T) -> T {
+    if x < T::ZERO { T::ZERO - x } else { x }
+}
+
+// Greatest common divisor, always non-negative. gcd(0, 0) is 0.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    abs(a)
+}
+
+// Least common multiple, always non-negative. Anything with 0 gives 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    abs(a / gcd(a, b) * b)
+}
+
+// The LCM of a whole list, like the cycle lengths of several ghosts walking at once. An empty list gives 1.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ONE, lcm)
+}
+
+// Returns (g, x, y) with a*x + b*y = g, where g = gcd(a, b). The coefficients can be negative, hence signed types only.
+pub fn extended_gcd<T: Integer + Neg<Output = T>>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// The x in 0..m with a*x ≡ 1 (mod m), if there is one (there isn't unless a and m are coprime)
+pub fn mod_inverse<T: Integer>(a: T, m: T) -> Option<T> {
+    let m = m.to_i128();
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.to_i128().rem_euclid(m), m);
+    if g != 1 {
+        return None;
+    }
+
+    T::from_i128(x.rem_euclid(m))
+}
+
+// base^exp mod m, in 0..m, by repeated squaring. m has to be positive.
+pub fn mod_pow<T: Integer>(base: T, mut exp: u64, m: T) -> T {
+    let m128 = m.to_i128();
+    assert!(m128 > 0, "mod_pow needs a positive modulus, got {:?}", m);
+
+    let m = m128 as u128;
+    let mut base = base.to_i128().rem_euclid(m128) as u128;
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+
+    // The result is below m, which came from a T, so it fits back in one
+    T::from_i128(result as i128).unwrap()
+}
+
+// The Chinese Remainder Theorem: given congruences x ≡ r (mod m) as (r, m) pairs, finds the x that satisfies all of
+// them, as (x, lcm of the moduli) with x in 0..lcm. The moduli don't have to be coprime; when they share factors the
+// congruences can contradict each other, and then the answer is None. None too if the combined modulus doesn't fit
+// in T. No congruences at all is satisfied by everything, which comes back as (0, 1).
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for &(r, m) in congruences {
+        let m = m.to_i128();
+        if m <= 0 {
+            return None;
+        }
+        let r = r.to_i128().rem_euclid(m);
+
+        // x + modulus*k ≡ r (mod m) has a solution for k only if gcd(modulus, m) divides the gap
+        let g = gcd(modulus, m);
+        let gap = r - x;
+        if gap % g != 0 {
+            return None;
+        }
+
+        // Solve (modulus/g)*k ≡ gap/g (mod m/g)
+        let step = m / g;
+        let inverse = mod_inverse(modulus / g, step)?;
+        let k = mul_mod((gap / g).rem_euclid(step) as u128, inverse as u128, step as u128) as i128;
+
+        let combined = modulus.checked_mul(step)?;
+        x = (x + modulus.checked_mul(k)?).rem_euclid(combined);
+        modulus = combined;
+    }
+
+    Some((T::from_i128(x)?, T::from_i128(modulus)?))
+}
+
+// a*b mod m for a and b below m. Multiplying straight out works until the product passes 2^128, which two numbers
+// below a modulus past 2^64 can do, so then it doubles and adds instead. Each step stays below 2m, and m came from an
+// i128, so nothing overflows.
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    let add = |x: u128, y: u128| if x + y >= m { x + y - m } else { x + y };
+    let (mut a, mut b, mut result) = (a, b, 0);
+
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add(result, a);
+        }
+        a = add(a, a);
+        b >>= 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-4i32, 6), 2);
+        assert_eq!(gcd(0u64, 0), 0);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(-4i64, 6), 12);
+        assert_eq!(lcm_all([2u64, 3, 4, 5]), 60);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3u32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i32, 11), Some(7));
+        assert_eq!(mod_inverse(4u32, 6), None);
+
+        assert_eq!(mod_pow(2u64, 10, 1000), 24);
+        assert_eq!(mod_pow(-2i64, 3, 5), 2);
+        assert_eq!(mod_pow(u64::MAX - 1, 2, u64::MAX), 1);
+
+        // A modulus past 2^64, where squaring anything near it overflows a u128
+        let m: i128 = (1 << 100) + 277;
+        assert_eq!(mod_pow(m - 2, 2, m), 4);
+        assert_eq!(mod_pow(3, 1_000_000_000_000_000_009, m), 317294174307155413363458948412);
+        assert_eq!(mod_pow(-5, (1 << 63) + 1, m), 1061318422646987134969241907949);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+
+        // 4 and 6 share a factor of 2: x ≡ 2 (mod 4) and x ≡ 4 (mod 6) meet at 10 (mod 12)...
+        assert_eq!(crt(&[(2i64, 4), (4, 6)]), Some((10, 12)));
+        // ...but one's odd and the other's even here, so nothing fits both
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+
+        assert_eq!(crt::<u32>(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1u8, 200), (0, 3)]), None);
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+
+// A cache for recursive functions. Instead of threading a &mut HashMap through every call (and remembering to check
+// it before recursing and fill it in after), the function is written as a closure that gets handed a `recurse`
+// callback, and Memo does the caching around every call that goes through it:
+//
+//     let mut memo = Memo::new();
+//     let fib = memo.call(80u64, &|&n, recurse| if n < 2 { n } else { recurse(n - 1) + recurse(n - 2) });
+//
+// The key is the whole argument, so anything the answer depends on has to be in it. Whatever stays the same across
+// calls (the grid, the list of springs) can just be captured by the closure.
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo { cache: HashMap::new(), hits: 0, misses: 0 }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The value of `f` at `key`, worked out at most once. `f` gets the key and a callback to use for its recursive
+    // calls; calling itself any other way skips the cache. The cache is kept between calls, so one Memo can answer
+    // several questions about the same input.
+    pub fn call<F>(&mut self, key: K, f: &F) -> V
+    where
+        F: Fn(&K, &mut dyn FnMut(K) -> V) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = f(&key, &mut |next| self.call(next, f));
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    // The cached value for a key, if it's been worked out
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    // Empties the cache and resets the counts
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    // How often the cache has had the answer already, for checking that the key is doing its job. A key that's
+    // too specific shows up as almost no hits.
+    pub fn stats(&self) -> MemoStats {
+        MemoStats { hits: self.hits, misses: self.misses, entries: self.cache.len() }
+    }
+}
+
+// A one-off memoized call, for when nothing needs to be kept afterwards
+pub fn memoize<K, V, F>(key: K, f: F) -> V
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+    F: Fn(&K, &mut dyn FnMut(K) -> V) -> V,
+{
+    Memo::new().call(key, &f)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+}
+
+impl MemoStats {
+    // The share of calls answered from the cache, between 0 and 1
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            calls => self.hits as f64 / calls as f64,
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} entries",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0,
+            self.entries,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caches_recursive_calls() {
+        let mut memo = Memo::new();
+        let fib = |&n: &u64, recurse: &mut dyn FnMut(u64) -> u64| if n < 2 { n } else { recurse(n - 1) + recurse(n - 2) };
+
+        assert_eq!(memo.call(90, &fib), 2880067194370816120);
+
+        // Each of fib(0..=90) is worked out once. From fib(3) up, the second recursive call is a hit.
+        let stats = memo.stats();
+        assert_eq!(stats.misses, 91);
+        assert_eq!(stats.entries, 91);
+        assert_eq!(stats.hits, 88);
+
+        // The cache sticks around for the next question
+        assert_eq!(memo.call(50, &fib), 12586269025);
+        assert_eq!(memo.stats().hits, 89);
+    }
+
+    #[test]
+    fn hot_springs() {
+        // 2023 day 12: the ways to fill in the ?s so the runs of # match the groups. The key is just how far into the
+        // row and the groups we've got; the row and the groups themselves are captured.
+        fn arrangements(row: &str, groups: &[usize]) -> u64 {
+            let row = row.as_bytes();
+
+            memoize((0, 0), |&(i, g): &(usize, usize), recurse| {
+                if i >= row.len() {
+                    return (g == groups.len()) as u64;
+                }
+
+                let mut count = 0;
+
+                if row[i] != b'#' {
+                    count += recurse((i + 1, g));
+                }
+
+                if row[i] != b'.' && g < groups.len() {
+                    let end = i + groups[g];
+                    let fits = end <= row.len() && row[i..end].iter().all(|&b| b != b'.');
+                    if fits && row.get(end) != Some(&b'#') {
+                        count += recurse((end + 1, g + 1));
+                    }
+                }
+
+                count
+            })
+        }
+
+        assert_eq!(arrangements("???.###", &[1, 1, 3]), 1);
+        assert_eq!(arrangements("?###????????", &[3, 2, 1]), 10);
+
+        let unfolded = ["?###????????"; 5].join("?");
+        assert_eq!(arrangements(&unfolded, &[3, 2, 1].repeat(5)), 506250);
+    }
+}
@@ -0,0 +1,321 @@
+use std::any::type_name;
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+
+// The bits of string wrangling nearly every day needs. Errors come back as AocError::Parse with the offending text in
+// the message. Functions that only see a single line report it as line 1; run them through parse_lines to get the
+// real line number filled in.
+
+// Every integer in a line, in order, ignoring whatever's around them: "x=-5, y=12..20" gives [-5, 12, 20]. A '-'
+// straight after a digit is read as a separator rather than a sign, so "3-7" is [3, 7].
+pub fn ints<T: FromStr>(line: &str) -> Result<Vec<T>, AocError> {
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let signed = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+        if !signed && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        numbers.push(parse_field(line, start, &line[start..i])?);
+    }
+
+    Ok(numbers)
+}
+
+// Splits the input into the groups separated by blank lines, dropping the blank lines themselves. Lines of nothing
+// but whitespace count as blank, and blank lines at either end don't make empty groups.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(s) = start.take() {
+                blocks.push(&input[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(s) = start {
+        blocks.push(&input[s..end]);
+    }
+
+    blocks
+}
+
+// The columns that are whitespace in every line (or past the end of it), which is how the puzzles that lay numbers
+// out in a table mark where one column stops and the next starts
+pub fn blank_columns<S: AsRef<str>>(lines: &[S]) -> Vec<usize> {
+    let width = lines.iter().map(|line| line.as_ref().len()).max().unwrap_or(0);
+
+    (0..width)
+        .filter(|&i| lines.iter().all(|line| line.as_ref().as_bytes().get(i).is_none_or(u8::is_ascii_whitespace)))
+        .collect()
+}
+
+// Cuts a line into fields at fixed byte positions, dropping the separator character at each one. A line that stops
+// short just gives empty fields at the end, so ragged tables (with their trailing spaces trimmed) still line up.
+pub fn split_columns<'a>(line: &'a str, separators: &[usize]) -> Vec<&'a str> {
+    let mut fields = Vec::with_capacity(separators.len() + 1);
+    let mut prev = 0;
+
+    for &i in separators {
+        fields.push(line.get(prev.min(line.len())..i.min(line.len())).unwrap_or(""));
+        prev = i + 1;
+    }
+
+    fields.push(line.get(prev.min(line.len())..).unwrap_or(""));
+    fields
+}
+
+// Runs `parse` over every line of the input, filling in the line number on any parse error it returns
+pub fn parse_lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, AocError>
+where
+    F: FnMut(&str) -> Result<T, AocError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| on_line(e, i + 1)))
+        .collect()
+}
+
+// Moves a parse error to the given line, for errors from parsers that only ever see one line and so say line 1
+pub fn on_line(error: AocError, line: usize) -> AocError {
+    match error {
+        AocError::Parse { column, message, .. } => AocError::Parse { line, column, message },
+        other => other,
+    }
+}
+
+fn parse_field<T: FromStr>(line: &str, offset: usize, field: &str) -> Result<T, AocError> {
+    field.parse().map_err(|_| {
+        let message = format!("{:?} isn't a valid {} (in {:?})", field, type_name::<T>(), line);
+        AocError::parse(1, offset + 1, message)
+    })
+}
+
+// A line format written the way the puzzle text shows it, with {} wherever a value goes:
+//
+//     let button = Pattern::new("Button {}: X+{}, Y+{}");
+//     let (name, x, y): (char, i64, i64) = button.parse("Button A: X+94, Y+34")?;
+//
+// Each {} matches up to the first place the text after it turns up, and a {} at the very end takes the rest of the
+// line. Two {}s need something between them to tell where one stops.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    pattern: String,
+    literals: Vec<String>,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Self {
+        let literals: Vec<String> = pattern.split("{}").map(String::from).collect();
+
+        let last = literals.len() - 1;
+        assert!(
+            literals.iter().enumerate().all(|(i, literal)| i == 0 || i == last || !literal.is_empty()),
+            "{:?} has two placeholders in a row, so there's no telling where one ends",
+            pattern,
+        );
+
+        Pattern { pattern: pattern.to_string(), literals }
+    }
+
+    // How many values the pattern picks out
+    pub fn placeholders(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    // The raw text of each placeholder
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Captures<'a>, AocError> {
+        let mismatch = |at: usize| {
+            AocError::parse(1, at + 1, format!("expected {:?}, got {:?}", self.pattern, line))
+        };
+
+        if !line.starts_with(self.literals[0].as_str()) {
+            return Err(mismatch(0));
+        }
+
+        let mut pos = self.literals[0].len();
+        let mut fields = Vec::with_capacity(self.placeholders());
+
+        for (i, literal) in self.literals.iter().enumerate().skip(1) {
+            let end = if i == self.literals.len() - 1 {
+                if !line.ends_with(literal.as_str()) || line.len() - literal.len() < pos {
+                    return Err(mismatch(pos));
+                }
+                line.len() - literal.len()
+            } else {
+                pos + line[pos..].find(literal.as_str()).ok_or_else(|| mismatch(pos))?
+            };
+
+            fields.push((pos, &line[pos..end]));
+            pos = end + literal.len();
+        }
+
+        Ok(Captures { line, fields, next: 0 })
+    }
+
+    // Matches the line and parses the values into a tuple, one element per placeholder, or a Vec if they're all the
+    // same type
+    pub fn parse<T: FromCaptures>(&self, line: &str) -> Result<T, AocError> {
+        let mut captures = self.captures(line)?;
+
+        if let Some(count) = T::FIELDS {
+            assert_eq!(count, self.placeholders(), "{:?} doesn't have one placeholder per value", self.pattern);
+        }
+
+        T::from_captures(&mut captures)
+    }
+
+    // parse over every line of the input
+    pub fn parse_lines<T: FromCaptures>(&self, input: &str) -> Result<Vec<T>, AocError> {
+        parse_lines(input, |line| self.parse(line))
+    }
+}
+
+// The values a Pattern matched, handed out in order
+#[derive(Debug, Clone)]
+pub struct Captures<'a> {
+    line: &'a str,
+    fields: Vec<(usize, &'a str)>,
+    next: usize,
+}
+
+impl<'a> Captures<'a> {
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn get(&self, i: usize) -> Option<&'a str> {
+        self.fields.get(i).map(|&(_, field)| field)
+    }
+
+    // Parses the next value. Asking for more values than there are placeholders is a bug, so it panics.
+    pub fn parse_next<T: FromStr>(&mut self) -> Result<T, AocError> {
+        let (offset, field) = self.fields[self.next];
+        self.next += 1;
+        parse_field(self.line, offset, field)
+    }
+}
+
+// What a Pattern can parse a line into
+pub trait FromCaptures: Sized {
+
+    // How many placeholders it takes, if that's fixed
+    const FIELDS: Option<usize>;
+
+    fn from_captures(captures: &mut Captures) -> Result<Self, AocError>;
+}
+
+impl<T: FromStr> FromCaptures for Vec<T> {
+    const FIELDS: Option<usize> = None;
+
+    fn from_captures(captures: &mut Captures) -> Result<Self, AocError> {
+        (0..captures.len()).map(|_| captures.parse_next()).collect()
+    }
+}
+
+macro_rules! tuple_captures {
+    ($count:expr; $($t:ident),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+) {
+            const FIELDS: Option<usize> = Some($count);
+
+            fn from_captures(captures: &mut Captures) -> Result<Self, AocError> {
+                Ok(($(captures.parse_next::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+tuple_captures!(1; A);
+tuple_captures!(2; A, B);
+tuple_captures!(3; A, B, C);
+tuple_captures!(4; A, B, C, D);
+tuple_captures!(5; A, B, C, D, E);
+tuple_captures!(6; A, B, C, D, E, F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_ints() -> Result<(), AocError> {
+        assert_eq!(ints::<i64>("Sensor at x=-5, y=12: closest beacon is at x=3, y=-40")?, vec![-5, 12, 3, -40]);
+        assert_eq!(ints::<u32>("2-4,6-8")?, vec![2, 4, 6, 8]);
+        assert!(ints::<u8>("no numbers here")?.is_empty());
+
+        // Doesn't fit in a u8
+        match ints::<u8>("a 7 300") {
+            Err(AocError::Parse { column, .. }) => assert_eq!(column, 5),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn splits_blocks() {
+        assert_eq!(blocks("1000\n2000\n\n4000\n\n\n5000\n6000\n"), vec!["1000\n2000", "4000", "5000\n6000"]);
+        assert_eq!(blocks("\r\na\r\n  \r\nb\r\n"), vec!["a", "b"]);
+        assert!(blocks("\n\n").is_empty());
+    }
+
+    #[test]
+    fn splits_columns() {
+        let rows = ["123 328  51 64", " 45 64  387 23", "  6 98  215 314"];
+        let separators = blank_columns(&rows);
+
+        assert_eq!(separators, vec![3, 7, 11]);
+        assert_eq!(split_columns(rows[0], &separators), vec!["123", "328", " 51", "64"]);
+        assert_eq!(split_columns("1", &separators), vec!["1", "", "", ""]);
+    }
+
+    #[test]
+    fn patterns() -> Result<(), AocError> {
+        let button = Pattern::new("Button {}: X+{}, Y+{}");
+        assert_eq!(button.parse::<(char, i64, i64)>("Button A: X+94, Y+34")?, ('A', 94, 34));
+
+        let machine = Pattern::new("[{}] {} {{}}");
+        let (lights, buttons, joltage): (String, String, String) = machine.parse("[.##.] (3) (1,3) {3,5,4,7}")?;
+        assert_eq!((lights.as_str(), buttons.as_str(), joltage.as_str()), (".##.", "(3) (1,3)", "3,5,4,7"));
+
+        let sizes = Pattern::new("{}x{}x{}");
+        assert_eq!(sizes.parse_lines::<Vec<u32>>("2x3x4\n1x1x10")?, vec![vec![2, 3, 4], vec![1, 1, 10]]);
+
+        // The second line is the bad one, and the error says so
+        match sizes.parse_lines::<(u32, u32, u32)>("2x3x4\n1x1\n") {
+            Err(AocError::Parse { line, message, .. }) => {
+                assert_eq!(line, 2);
+                assert!(message.contains("1x1"));
+            },
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt;
+use std::ops::{ Add, Mul, Neg, Sub };
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::parse::ints;
+
+
+// Integer points in the plane and in space. Distances are kept exact: Manhattan distance, and Euclidean distance
+// squared (comparing squared distances orders points the same way as comparing the distances, without a square root
+// or a float anywhere). Coordinates are i64, so differences up to about 3 billion per axis square without overflow.
+
+// Anything with integer coordinates along D axes, which is what the spatial index works on
+pub trait Coords<const D: usize>: Copy {
+    fn coords(&self) -> [i64; D];
+
+    fn dist_sq(&self, other: &Self) -> i64 {
+        let (a, b) = (self.coords(), other.coords());
+        (0..D).map(|i| (a[i] - b[i]) * (a[i] - b[i])).sum()
+    }
+
+    fn manhattan(&self, other: &Self) -> i64 {
+        let (a, b) = (self.coords(), other.coords());
+        (0..D).map(|i| (a[i] - b[i]).abs()).sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl Coords<2> for Point2 {
+    fn coords(&self) -> [i64; 2] {
+        [self.x, self.y]
+    }
+}
+
+impl Coords<3> for Point3 {
+    fn coords(&self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+// The arithmetic is the same for both, one axis at a time
+macro_rules! point_ops {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, k: i64) -> $point {
+                $point { $($axis: self.$axis * k),+ }
+            }
+        }
+
+        // Parses the numbers out of lines like "162,817,812" or "x=3, y=-4"
+        impl FromStr for $point {
+            type Err = AocError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                const AXES: usize = [$(stringify!($axis)),+].len();
+
+                match ints::<i64>(s)?[..] {
+                    [$($axis),+] => Ok($point { $($axis),+ }),
+                    _ => Err(AocError::parse(1, 1, format!("expected {} numbers, got {:?}", AXES, s))),
+                }
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() -> Result<(), AocError> {
+        let a: Point3 = "162,817,812".parse()?;
+        let b: Point3 = "425,690,689".parse()?;
+
+        assert_eq!(a.dist_sq(&b), 263 * 263 + 127 * 127 + 123 * 123);
+        assert_eq!(a.manhattan(&b), 263 + 127 + 123);
+        assert_eq!((b - a) + a, b);
+        assert_eq!(a.to_string(), "162,817,812");
+
+        // Far enough apart that an f32 can't tell these distances apart
+        let origin = Point3::ORIGIN;
+        assert!(origin.dist_sq(&Point3::new(100_000_000, 0, 0)) < origin.dist_sq(&Point3::new(100_000_000, 1, 0)));
+
+        assert_eq!("x=-3, y=4".parse::<Point2>()?, Point2::new(-3, 4));
+        assert_eq!(Point2::ORIGIN.dist_sq(&Point2::new(-3, 4)), 25);
+        assert!("1,2".parse::<Point3>().is_err());
+        Ok(())
+    }
+}
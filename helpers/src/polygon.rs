@@ -0,0 +1,243 @@
+use crate::math::gcd;
+
+
+// A simple polygon (one that doesn't cross itself) with its corners on the integer grid, given in order around the
+// outside; either direction works. This covers both the trench/loop puzzles, where the question is how many tiles
+// the loop encloses, and the "is this rectangle inside the shape" ones. Everything is done in integers, so there's
+// no floating point fuzz at the edges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<(i64, i64)>,
+}
+
+// Where a point is relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    Outside,
+    Boundary,
+    Inside,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<(i64, i64)>) -> Self {
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[(i64, i64)] {
+        &self.vertices
+    }
+
+    // Each side as a pair of corners, including the one that closes the loop
+    pub fn edges(&self) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    // Twice the area, from the shoelace formula. Lattice polygons can have half-integer areas, and doubling keeps
+    // things exact.
+    pub fn double_area(&self) -> i64 {
+        self.edges().map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1).sum::<i64>().abs()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+
+    // How many grid points lie on the outline, corners included
+    pub fn boundary_points(&self) -> i64 {
+        self.edges().map(|((x1, y1), (x2, y2))| gcd(x2 - x1, y2 - y1)).sum()
+    }
+
+    // How many grid points lie strictly inside, from Pick's theorem: A = I + B/2 - 1
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    // Every grid point inside or on the outline. When the outline is a loop through the middle of some tiles, this
+    // is the number of tiles the loop and what it encloses cover.
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn locate(&self, (x, y): (i64, i64)) -> Containment {
+        locate(&self.vertices, (2 * x, 2 * y))
+    }
+
+    // Inside or on the boundary
+    pub fn contains(&self, point: (i64, i64)) -> bool {
+        self.locate(point) != Containment::Outside
+    }
+
+    // Whether the axis-aligned rectangle with these opposite corners lies entirely inside the polygon, boundary
+    // included. A rectangle can run along the outline and still count.
+    pub fn contains_rect(&self, (x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> bool {
+        let (left, right) = (x1.min(x2), x1.max(x2));
+        let (top, bottom) = (y1.min(y2), y1.max(y2));
+
+        if left == right || top == bottom {
+            return self.contains_segment((left, top), (right, bottom));
+        }
+
+        // If the outline doesn't pass through the open interior of the rectangle, the whole interior is on one side
+        // of it, and the middle of the rectangle says which
+        let corners = [(left, top), (right, top), (right, bottom), (left, bottom)];
+        if self.edges().any(|edge| enters_box(edge, left, right, top, bottom, &corners)) {
+            return false;
+        }
+
+        locate(&self.vertices, (left + right, top + bottom)) != Containment::Outside
+    }
+
+    // A rectangle with no width or height: a straight segment (or a single point) that has to lie inside
+    fn contains_segment(&self, a: (i64, i64), b: (i64, i64)) -> bool {
+        // If an edge cuts straight across the segment, part of the segment is on the outside of that edge
+        if self.edges().any(|(p, q)| crosses(a, b, p, q)) {
+            return false;
+        }
+
+        // Otherwise the outline only meets the segment at corners lying on it and along edges running over it. Between
+        // those points each piece is entirely in or entirely out, so checking each piece's midpoint settles it.
+        let along = |(x, y): (i64, i64)| (x - a.0) + (y - a.1);
+        let mut stops: Vec<(i64, i64)> = self
+            .vertices
+            .iter()
+            .copied()
+            .filter(|&v| on_segment(v, a, b))
+            .chain([a, b])
+            .collect();
+        stops.sort_by_key(|&p| along(p));
+        stops.dedup();
+
+        stops.iter().all(|&p| self.contains(p))
+            && stops.windows(2).all(|w| {
+                locate(&self.vertices, (w[0].0 + w[1].0, w[0].1 + w[1].1)) != Containment::Outside
+            })
+    }
+}
+
+// Point in polygon by counting edge crossings, with the point given in doubled coordinates so that midpoints between
+// grid points can be asked about too
+fn locate(vertices: &[(i64, i64)], (px, py): (i64, i64)) -> Containment {
+    let n = vertices.len();
+    let mut inside = false;
+
+    for i in 0..n {
+        let (ax, ay) = (2 * vertices[i].0, 2 * vertices[i].1);
+        let (bx, by) = (2 * vertices[(i + 1) % n].0, 2 * vertices[(i + 1) % n].1);
+
+        if on_segment((px, py), (ax, ay), (bx, by)) {
+            return Containment::Boundary;
+        }
+
+        // Each edge owns its lower end but not its upper one, so a ray through a corner is only counted once
+        if (ay > py) != (by > py) {
+            let side = cross((ax, ay), (bx, by), (px, py));
+            if (by > ay) == (side > 0) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside { Containment::Inside } else { Containment::Outside }
+}
+
+// Which side of the line through a and b the point p is on: positive for one side, negative for the other, zero on it
+fn cross(a: (i64, i64), b: (i64, i64), p: (i64, i64)) -> i64 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+fn on_segment(p: (i64, i64), a: (i64, i64), b: (i64, i64)) -> bool {
+    cross(a, b, p) == 0
+        && a.0.min(b.0) <= p.0 && p.0 <= a.0.max(b.0)
+        && a.1.min(b.1) <= p.1 && p.1 <= a.1.max(b.1)
+}
+
+// Whether segments ab and pq cross at a single point that's strictly inside both
+fn crosses(a: (i64, i64), b: (i64, i64), p: (i64, i64), q: (i64, i64)) -> bool {
+    let (d1, d2) = (cross(a, b, p).signum(), cross(a, b, q).signum());
+    let (d3, d4) = (cross(p, q, a).signum(), cross(p, q, b).signum());
+    d1 * d2 < 0 && d3 * d4 < 0
+}
+
+// Whether a (closed) edge reaches into the open box left < x < right, top < y < bottom. They miss each other exactly
+// when something separates them: the edge is entirely to one side of the box, or the whole box is on one side of the
+// edge's line.
+fn enters_box(
+    (p, q): ((i64, i64), (i64, i64)),
+    left: i64,
+    right: i64,
+    top: i64,
+    bottom: i64,
+    corners: &[(i64, i64); 4],
+) -> bool {
+    if p.0.max(q.0) <= left || p.0.min(q.0) >= right || p.1.max(q.1) <= top || p.1.min(q.1) >= bottom {
+        return false;
+    }
+
+    let sides = corners.map(|corner| cross(p, q, corner));
+    !(sides.iter().all(|&s| s >= 0) || sides.iter().all(|&s| s <= 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The red tiles from 2025 day 9's example
+    fn tiles() -> Polygon {
+        Polygon::new(vec![(7, 1), (11, 1), (11, 7), (9, 7), (9, 5), (2, 5), (2, 3), (7, 3)])
+    }
+
+    #[test]
+    fn picks_theorem() {
+        // 2023 day 18's dig plan traces this loop through the middle of the trench tiles
+        let lagoon = Polygon::new(vec![
+            (0, 0), (6, 0), (6, 5), (4, 5), (4, 7), (6, 7), (6, 9), (1, 9), (1, 7), (0, 7), (0, 5), (2, 5), (2, 2), (0, 2),
+        ]);
+
+        assert_eq!(lagoon.boundary_points(), 38);
+        assert_eq!(lagoon.lattice_points(), 62);
+
+        let triangle = Polygon::new(vec![(0, 0), (4, 0), (0, 3)]);
+        assert_eq!(triangle.double_area(), 12);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[test]
+    fn locates_points() {
+        let tiles = tiles();
+
+        assert_eq!(tiles.locate((8, 2)), Containment::Inside);
+        assert_eq!(tiles.locate((9, 1)), Containment::Boundary);
+        assert_eq!(tiles.locate((2, 4)), Containment::Boundary);
+        assert_eq!(tiles.locate((3, 2)), Containment::Outside);
+        assert_eq!(tiles.locate((10, 8)), Containment::Outside);
+
+        // Level with a horizontal edge, and lined up with corners, which is where ray casting usually goes wrong
+        assert_eq!(tiles.locate((1, 3)), Containment::Outside);
+        assert_eq!(tiles.locate((1, 5)), Containment::Outside);
+        assert_eq!(tiles.locate((12, 1)), Containment::Outside);
+        assert_eq!(tiles.locate((5, 4)), Containment::Inside);
+    }
+
+    #[test]
+    fn rectangles() {
+        let tiles = tiles();
+
+        assert!(tiles.contains_rect((9, 5), (2, 3)));
+        assert!(tiles.contains_rect((7, 3), (11, 1)));
+        assert!(tiles.contains_rect((9, 7), (9, 5)));
+        assert!(!tiles.contains_rect((2, 5), (11, 1)));
+        assert!(!tiles.contains_rect((2, 3), (11, 7)));
+
+        // Starts along an edge, crosses the middle, and then leaves through the bottom
+        assert!(!tiles.contains_rect((7, 1), (7, 7)));
+        assert!(tiles.contains_rect((7, 1), (7, 5)));
+
+        // A U shape, where the gap between the arms is bounded by edges but isn't inside
+        let u = Polygon::new(vec![(0, 0), (2, 0), (2, 4), (4, 4), (4, 0), (6, 0), (6, 6), (0, 6)]);
+        assert!(!u.contains_rect((2, 0), (4, 4)));
+        assert!(!u.contains_rect((2, 0), (4, 0)));
+        assert!(u.contains_rect((0, 4), (6, 6)));
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::{ self, Debug, Display };
+use std::ops::{ Add, Sub };
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+
+// The integer types an Interval can hold. Everything AoC uses (u32, u64, usize, i64...) qualifies.
+pub trait Bound: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> + From<u8> {}
+
+impl<T: Copy + Ord + Debug + Add<Output = T> + Sub<Output = T> + From<u8>> Bound for T {}
+
+fn one<T: Bound>() -> T {
+    T::from(1)
+}
+
+// An inclusive range of integers, start..=end, which is how the puzzles write them ("3-7" covers 3 through 7).
+// start <= end always holds, so an Interval is never empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Bound> Interval<T> {
+
+    // None if end comes before start
+    pub fn new(start: T, end: T) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn len(&self) -> T {
+        self.end - self.start + one()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    // What's left of this interval with `other` taken out: nothing, one piece, or a piece either side
+    pub fn difference(&self, other: &Interval<T>) -> Vec<Interval<T>> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        let mut pieces = Vec::new();
+        if self.start < other.start {
+            pieces.push(Interval { start: self.start, end: other.start - one() });
+        }
+        if other.end < self.end {
+            pieces.push(Interval { start: other.end + one(), end: self.end });
+        }
+
+        pieces
+    }
+}
+
+// Parses "start-end". Negative numbers work too ("-5--2"), since the split is on the first '-' after a digit.
+impl<T: Bound + FromStr> FromStr for Interval<T> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad = || AocError::parse(1, 1, format!("expected start-end, got {:?}", s));
+
+        let s = s.trim();
+        let split = s.char_indices().skip(1).find(|&(i, ch)| ch == '-' && s.as_bytes()[i - 1].is_ascii_digit());
+        let (i, _) = split.ok_or_else(bad)?;
+
+        let start = s[..i].parse().map_err(|_| bad())?;
+        let end = s[i + 1..].parse().map_err(|_| bad())?;
+
+        Interval::new(start, end).ok_or_else(bad)
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// A set of integers stored as sorted, disjoint intervals. Overlapping and touching intervals are merged on the way
+// in, so there's exactly one way to store any set and two RangeSets are equal when they cover the same numbers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        RangeSet { intervals: Vec::new() }
+    }
+}
+
+impl<T: Bound> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut merged = interval;
+
+        // Everything that overlaps or touches the new interval gets folded into it
+        let first = self.intervals.partition_point(|i| i.end < merged.start && merged.start - i.end > one());
+        let mut last = first;
+
+        while last < self.intervals.len() && !(self.intervals[last].start > merged.end && self.intervals[last].start - merged.end > one()) {
+            merged.start = merged.start.min(self.intervals[last].start);
+            merged.end = merged.end.max(self.intervals[last].end);
+            last += 1;
+        }
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end < value);
+        self.intervals.get(i).is_some_and(|interval| interval.contains(value))
+    }
+
+    // How many integers the set covers
+    pub fn len(&self) -> T {
+        self.intervals.iter().fold(T::from(0), |total, interval| total + interval.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn union(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut union = self.clone();
+        for interval in &other.intervals {
+            union.insert(*interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        self.intervals
+            .iter()
+            .flat_map(|a| other.intervals.iter().filter_map(move |b| a.intersection(b)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &RangeSet<T>) -> RangeSet<T> {
+        let mut pieces = self.intervals.clone();
+
+        for cut in &other.intervals {
+            pieces = pieces.iter().flat_map(|piece| piece.difference(cut)).collect();
+        }
+
+        pieces.into_iter().collect()
+    }
+
+    // Sends every number through the mappings (see map_interval)
+    pub fn map(&self, mappings: &[Mapping<T>]) -> RangeSet<T> {
+        self.intervals.iter().flat_map(|interval| map_interval(interval, mappings)).collect()
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+// Moves the numbers in `source` so that source.start lands on `destination`, the way the seed/soil/fertilizer style
+// puzzles describe their maps ("destination source length")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mapping<T> {
+    pub source: Interval<T>,
+    pub destination: T,
+}
+
+impl<T: Bound> Mapping<T> {
+    // None for an empty mapping, which doesn't move anything
+    pub fn new(destination: T, source: T, length: T) -> Option<Self> {
+        if length <= T::from(0) {
+            return None;
+        }
+
+        let source = Interval::new(source, source + length - one())?;
+        Some(Mapping { source, destination })
+    }
+
+    fn apply(&self, interval: &Interval<T>) -> Interval<T> {
+        Interval {
+            start: interval.start - self.source.start + self.destination,
+            end: interval.end - self.source.start + self.destination,
+        }
+    }
+}
+
+// Splits an interval against a set of (non-overlapping) mappings: the parts a mapping covers are moved by it, and
+// the parts no mapping covers come through unchanged
+pub fn map_interval<T: Bound>(interval: &Interval<T>, mappings: &[Mapping<T>]) -> Vec<Interval<T>> {
+    let mut unmapped = vec![*interval];
+    let mut mapped = Vec::new();
+
+    for mapping in mappings {
+        let mut rest = Vec::new();
+
+        for piece in unmapped {
+            match piece.intersection(&mapping.source) {
+                Some(hit) => {
+                    mapped.push(mapping.apply(&hit));
+                    rest.extend(piece.difference(&mapping.source));
+                },
+                None => rest.push(piece),
+            }
+        }
+
+        unmapped = rest;
+    }
+
+    mapped.extend(unmapped);
+    mapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn iv(start: i64, end: i64) -> Interval<i64> {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> RangeSet<i64> {
+        intervals.iter().map(|&(a, b)| iv(a, b)).collect()
+    }
+
+    #[test]
+    fn intervals() -> Result<(), AocError> {
+        assert_eq!("3-5".parse::<Interval<u64>>()?, Interval { start: 3, end: 5 });
+        assert_eq!("-5--2".parse::<Interval<i64>>()?, iv(-5, -2));
+        assert!("5-3".parse::<Interval<u64>>().is_err());
+        assert!("5".parse::<Interval<u64>>().is_err());
+
+        assert_eq!(iv(3, 5).len(), 3);
+        assert!(iv(2, 8).contains_interval(&iv(3, 7)));
+        assert!(iv(5, 7).overlaps(&iv(7, 9)));
+        assert!(!iv(2, 4).overlaps(&iv(6, 8)));
+        assert_eq!(iv(2, 8).difference(&iv(4, 5)), vec![iv(2, 3), iv(6, 8)]);
+        assert_eq!(iv(2, 8).difference(&iv(0, 10)), vec![]);
+        Ok(())
+    }
+
+    #[test]
+    fn merges_on_insert() {
+        // The fresh ingredient ranges from 2025 day 5's example
+        let fresh = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+
+        assert_eq!(fresh.intervals(), &[iv(3, 5), iv(10, 20)]);
+        assert_eq!(fresh.len(), 14);
+        assert_eq!([1, 5, 8, 11, 17, 32].iter().filter(|&&id| fresh.contains(id)).count(), 3);
+
+        // Touching intervals merge too, since there's no integer between them
+        assert_eq!(set(&[(1, 2), (3, 4)]).intervals(), &[iv(1, 4)]);
+        assert_eq!(set(&[(1, 2), (4, 5)]).intervals().len(), 2);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (26, 30)]));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn splits_against_mappings() {
+        // seed-to-soil from 2023 day 5: "50 98 2" and "52 50 48"
+        let mappings = [Mapping::new(50, 98, 2).unwrap(), Mapping::new(52, 50, 48).unwrap()];
+        assert_eq!(Mapping::new(10u64, 0, 0), None);
+
+        let mut pieces = map_interval(&iv(45, 99), &mappings);
+        pieces.sort();
+        assert_eq!(pieces, vec![iv(45, 49), iv(50, 51), iv(52, 99)]);
+
+        assert_eq!(set(&[(79, 92), (55, 67)]).map(&mappings), set(&[(81, 94), (57, 69)]));
+    }
+}
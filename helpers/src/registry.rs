@@ -0,0 +1,195 @@
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use serde::Deserialize;
+
+use crate::error::AocError;
+
+
+// Every day crate under the year workspaces, found by walking the repo. Years are four-digit directories with a
+// workspace Cargo.toml, and days are their day-NN members. A day's parts are its part1/part2 binaries, however
+// they're named in the manifest.
+#[derive(Debug, Clone)]
+pub struct Registry {
+    pub root: PathBuf,
+    pub solutions: Vec<SolutionEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionEntry {
+    pub year: u16,
+    pub day: u8,
+    pub package: String,
+    pub dir: PathBuf,
+    pub parts: Vec<PartBin>,
+}
+
+// The binary that solves one part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartBin {
+    pub part: u8,
+    pub bin: String,
+}
+
+impl Registry {
+
+    // The repo this crate was built from, unless AOC_ROOT says otherwise
+    pub fn default_root() -> PathBuf {
+        match std::env::var_os("AOC_ROOT") {
+            Some(root) => PathBuf::from(root),
+            None => Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf(),
+        }
+    }
+
+    pub fn discover(root: &Path) -> Result<Self, AocError> {
+        let mut solutions = Vec::new();
+
+        for year_dir in sorted_dirs(root)? {
+            let Some(year) = dir_name(&year_dir).filter(|n| n.len() == 4).and_then(|n| n.parse::<u16>().ok()) else {
+                continue;
+            };
+
+            if !year_dir.join("Cargo.toml").is_file() {
+                continue;
+            }
+
+            for day_dir in sorted_dirs(&year_dir)? {
+                let Some(day) = dir_name(&day_dir).and_then(|n| n.strip_prefix("day-")).and_then(|d| d.parse::<u8>().ok()) else {
+                    continue;
+                };
+
+                if let Some(entry) = read_entry(year, day, &day_dir)? {
+                    solutions.push(entry);
+                }
+            }
+        }
+
+        Ok(Self { root: root.to_path_buf(), solutions })
+    }
+
+    pub fn find(&self, year: u16, day: u8) -> Option<&SolutionEntry> {
+        self.solutions.iter().find(|s| s.year == year && s.day == day)
+    }
+
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &SolutionEntry> {
+        self.solutions.iter().filter(move |s| s.year == year)
+    }
+}
+
+impl SolutionEntry {
+    pub fn bin(&self, part: u8) -> Option<&str> {
+        self.parts.iter().find(|p| p.part == part).map(|p| p.bin.as_str())
+    }
+
+    // The workspace manifest this day builds under
+    pub fn workspace_manifest(&self) -> PathBuf {
+        self.dir.parent().unwrap().join("Cargo.toml")
+    }
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    package: Package,
+    #[serde(default)]
+    bin: Vec<BinTarget>,
+}
+
+#[derive(Deserialize)]
+struct Package {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct BinTarget {
+    name: String,
+    path: Option<String>,
+}
+
+fn read_entry(year: u16, day: u8, dir: &Path) -> Result<Option<SolutionEntry>, AocError> {
+    let manifest_path = dir.join("Cargo.toml");
+    if !manifest_path.is_file() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&manifest_path).map_err(|e| AocError::io(&manifest_path, e))?;
+    let manifest: Manifest = toml::from_str(&contents).map_err(|e| toml_error(&contents, e))?;
+
+    let mut parts = Vec::new();
+
+    for part in [1u8, 2] {
+        let file = format!("src/bin/part{}.rs", part);
+        if !dir.join(&file).is_file() {
+            continue;
+        }
+
+        // An explicit [[bin]] pointing at the file wins over the name cargo would infer from it
+        let bin = manifest.bin
+            .iter()
+            .find(|b| b.path.as_deref() == Some(file.as_str()))
+            .map_or(format!("part{}", part), |b| b.name.clone());
+
+        parts.push(PartBin { part, bin });
+    }
+
+    if parts.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(SolutionEntry { year, day, package: manifest.package.name, dir: dir.to_path_buf(), parts }))
+}
+
+// Turns a toml error's byte span into a line and column
+pub(crate) fn toml_error(contents: &str, e: toml::de::Error) -> AocError {
+    let offset = e.span().map_or(0, |s| s.start);
+    let before = &contents[..offset.min(contents.len())];
+
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+
+    AocError::parse(line, column, e.message())
+}
+
+fn sorted_dirs(dir: &Path) -> Result<Vec<PathBuf>, AocError> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| AocError::io(dir, e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_dir())
+        .collect();
+
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn dir_name(path: &Path) -> Option<&str> {
+    path.file_name().and_then(|n| n.to_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discovers_the_year_workspaces() -> Result<(), AocError> {
+        let registry = Registry::discover(&Registry::default_root())?;
+
+        let day = registry.find(2025, 5).expect("2025 day 5 should be registered");
+        assert_eq!(day.package, "day-05");
+        assert_eq!(day.bin(1), Some("part1"));
+        assert_eq!(day.bin(2), Some("part2"));
+
+        // 2024 day 10 and 2023 day 17 only have a part 1, and 2023 skipped day 5
+        assert_eq!(registry.find(2024, 10).map(|d| d.parts.len()), Some(1));
+        assert_eq!(registry.year(2023).count(), 16);
+        assert!(registry.find(2023, 5).is_none());
+        assert_eq!(registry.find(2023, 17).map(|d| (d.bin(1), d.bin(2))), Some((Some("part1"), None)));
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_bin_names_are_respected() -> Result<(), AocError> {
+        let registry = Registry::discover(&Registry::default_root())?;
+
+        assert_eq!(registry.find(2024, 5).and_then(|d| d.bin(1)), Some("day05"));
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::{ Command, Stdio };
+use std::time::{ Duration, Instant };
+
+use serde::Deserialize;
+
+use crate::bench::BenchResult;
+use crate::error::AocError;
+use crate::registry::SolutionEntry;
+use crate::source::{ InputSource, RunArgs };
+
+
+// Runs one part of a registered solution: builds its binary in release mode under the year's workspace, runs it from
+// the day's directory (so relative example paths keep working) and times it. The input source and any --set
+// parameters are passed straight through.
+#[derive(Debug, Clone)]
+pub struct RunOutcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub stdout: String,
+    pub elapsed: Duration,
+}
+
+pub fn run_part(entry: &SolutionEntry, part: u8, args: &RunArgs) -> Result<RunOutcome, AocError> {
+    let (stdout, elapsed) = execute(entry, part, args, &[])?;
+    let answer = extract_answer(&stdout).ok_or_else(|| failed(entry, part, "printed nothing"))?;
+
+    Ok(RunOutcome { year: entry.year, day: entry.day, part, answer, stdout, elapsed })
+}
+
+// Runs one part in benchmark mode, where the binary times `runs` parse/solve rounds itself so process startup and
+// input fetching stay out of the numbers
+pub fn bench_part(entry: &SolutionEntry, part: u8, args: &RunArgs, runs: usize) -> Result<(String, BenchResult), AocError> {
+    let (stdout, _) = execute(entry, part, args, &["--bench".to_string(), runs.to_string()])?;
+
+    let answer = extract_answer(&stdout).ok_or_else(|| failed(entry, part, "printed nothing"))?;
+    let result = stdout
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix("Bench:"))
+        .ok_or_else(|| failed(entry, part, "didn't print any benchmark results; does it use run_solution?"))?;
+
+    let result = serde_json::from_str(result.trim()).map_err(|e| failed(entry, part, format!("bad benchmark results: {}", e)))?;
+
+    Ok((answer, result))
+}
+
+// Builds and runs a part's binary, returning what it printed and how long it took
+fn execute(entry: &SolutionEntry, part: u8, args: &RunArgs, extra: &[String]) -> Result<(String, Duration), AocError> {
+    let executable = build_part(entry, part)?;
+
+    let mut command = Command::new(&executable);
+    command
+        .current_dir(&entry.dir)
+        .args(forwarded_args(args)?)
+        .args(extra)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit());
+
+    if args.source != InputSource::Stdin {
+        command.stdin(Stdio::null());
+    }
+
+    let now = Instant::now();
+    let output = command.output().map_err(|e| AocError::io(&executable, e))?;
+    let elapsed = now.elapsed();
+
+    if !output.status.success() {
+        return Err(failed(entry, part, format!("exited with {}", output.status)));
+    }
+
+    Ok((String::from_utf8_lossy(&output.stdout).into_owned(), elapsed))
+}
+
+fn failed(entry: &SolutionEntry, part: u8, message: impl Into<String>) -> AocError {
+    AocError::Solution { year: entry.year, day: entry.day, part, message: message.into() }
+}
+
+// Builds a part's binary and returns the path cargo put it at
+pub fn build_part(entry: &SolutionEntry, part: u8) -> Result<PathBuf, AocError> {
+    let bin = entry.bin(part).ok_or(AocError::NoSolution { year: entry.year, day: entry.day, part: Some(part) })?;
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest = entry.workspace_manifest();
+
+    let output = Command::new(&cargo)
+        .args(["build", "--release", "--message-format=json-render-diagnostics", "--quiet"])
+        .arg("--manifest-path").arg(&manifest)
+        .args(["-p", &entry.package, "--bin", bin])
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| AocError::io(&cargo, e))?;
+
+    if !output.status.success() {
+        return Err(failed(entry, part, format!("cargo build exited with {}", output.status)));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Artifact>(line).ok())
+        .find(|artifact| artifact.reason == "compiler-artifact" && artifact.target.name == bin)
+        .and_then(|artifact| artifact.executable)
+        .ok_or_else(|| failed(entry, part, format!("cargo didn't report an executable for {}", bin)))
+}
+
+// The bits of cargo's JSON build messages we care about
+#[derive(Deserialize)]
+struct Artifact {
+    reason: String,
+    target: Target,
+    executable: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+struct Target {
+    name: String,
+}
+
+// The solution runs from its own directory, so any relative file path has to be resolved against ours first
+fn forwarded_args(args: &RunArgs) -> Result<Vec<String>, AocError> {
+    let source = match &args.source {
+        InputSource::File(path) if path.is_relative() => {
+            let cwd = env::current_dir().map_err(|e| AocError::io(".", e))?;
+            InputSource::File(cwd.join(path))
+        },
+        source => source.clone(),
+    };
+
+    let mut forwarded = vec!["--input".to_string(), source.to_string()];
+
+    for (key, value) in &args.params {
+        forwarded.push("--set".to_string());
+        forwarded.push(format!("{}={}", key, value));
+    }
+
+    Ok(forwarded)
+}
+
+// Pulls the answer out of a solution's output. Most solutions print "Result: <answer>" followed by a timing, the
+// older ones just print the answer on its own; either way it's the last thing they say that looks like one.
+pub fn extract_answer(stdout: &str) -> Option<String> {
+    let line = stdout
+        .lines()
+        .rev()
+        .find(|line| line.starts_with("Result:"))
+        .or_else(|| stdout.lines().rev().find(|line| !line.trim().is_empty()))?;
+
+    let answer = line.strip_prefix("Result:").unwrap_or(line);
+    let answer = answer.split(", execution time").next().unwrap_or(answer);
+
+    Some(answer.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_answers() {
+        assert_eq!(extract_answer("24000\n"), Some("24000".to_string()));
+        assert_eq!(extract_answer("Result: 3, execution time: 1.2ms\n"), Some("3".to_string()));
+        assert_eq!(extract_answer("Result: 3\nExecution time: 1.2ms\n"), Some("3".to_string()));
+        assert_eq!(extract_answer("\n\n"), None);
+    }
+
+    #[test]
+    fn relative_files_are_made_absolute() -> Result<(), AocError> {
+        let args = RunArgs::parse(["--input", "input.txt", "--set", "pairs=10"].map(String::from))?;
+        let forwarded = forwarded_args(&args)?;
+
+        let expected = format!("file:{}", env::current_dir().unwrap().join("input.txt").display());
+        assert_eq!(forwarded, vec!["--input".to_string(), expected, "--set".to_string(), "pairs=10".to_string()]);
+        Ok(())
+    }
+}
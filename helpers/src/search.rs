@@ -0,0 +1,220 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{ BinaryHeap, HashMap };
+use std::hash::Hash;
+use std::ops::Add;
+
+
+// Weighted shortest paths over any state a puzzle cares to define. A state is whatever you need to know to decide
+// where you can go next, so for the crucible puzzles it's (position, direction, steps in a straight line) rather than
+// just the position. States are discovered lazily through the `neighbours` closure, so there's no need to enumerate
+// them all up front or to fill a distance table with MAX.
+
+// The cheapest route found: its total cost and every state along it, start and goal included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+// Dijkstra from `start` to the first state that satisfies `goal`. `neighbours` returns (next state, cost of the move)
+// pairs; costs must not be negative.
+pub fn dijkstra<S, C, N, I, G>(start: S, neighbours: N, goal: G) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+// A* from `start` to the first state that satisfies `goal`. `heuristic` estimates the remaining cost from a state and
+// has to be admissible (never more than the real cost) for the answer to be the cheapest; Manhattan distance to the
+// goal is the usual choice on a grid.
+pub fn astar<S, C, N, I, H, G>(start: S, mut neighbours: N, mut heuristic: H, mut goal: G) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    // Every state seen gets an index, and the bookkeeping is done by index so a state is only hashed on discovery
+    let mut states: Vec<S> = vec![start.clone()];
+    let mut index: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut best: Vec<C> = vec![C::default()];
+    let mut parent: Vec<usize> = vec![usize::MAX];
+
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, current))) = heap.pop() {
+
+        // A cheaper route to this state was found after this entry was queued
+        if cost > best[current] {
+            continue;
+        }
+
+        if goal(&states[current]) {
+            return Some(Path { cost, states: walk_back(&states, &parent, current) });
+        }
+
+        for (next, step) in neighbours(&states[current]) {
+            let next_cost = cost + step;
+
+            let i = match index.entry(next) {
+                Entry::Occupied(entry) => {
+                    let i = *entry.get();
+                    if next_cost >= best[i] {
+                        continue;
+                    }
+                    best[i] = next_cost;
+                    parent[i] = current;
+                    i
+                },
+                Entry::Vacant(entry) => {
+                    let i = states.len();
+                    states.push(entry.key().clone());
+                    entry.insert(i);
+                    best.push(next_cost);
+                    parent.push(current);
+                    i
+                },
+            };
+
+            heap.push(Reverse((next_cost + heuristic(&states[i]), next_cost, i)));
+        }
+    }
+
+    None
+}
+
+// The cheapest cost from `start` to every state reachable from it
+pub fn dijkstra_all<S, C, N, I>(start: S, mut neighbours: N) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut dist: HashMap<S, C> = HashMap::from([(start.clone(), C::default())]);
+    let mut states: Vec<S> = vec![start];
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, current))) = heap.pop() {
+        if cost > dist[&states[current]] {
+            continue;
+        }
+
+        for (next, step) in neighbours(&states[current]) {
+            let next_cost = cost + step;
+
+            if dist.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+
+            dist.insert(next.clone(), next_cost);
+            states.push(next);
+            heap.push(Reverse((next_cost, states.len() - 1)));
+        }
+    }
+
+    dist
+}
+
+fn walk_back<S: Clone>(states: &[S], parent: &[usize], mut current: usize) -> Vec<S> {
+    let mut path = vec![states[current].clone()];
+
+    while parent[current] != usize::MAX {
+        current = parent[current];
+        path.push(states[current].clone());
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dir::Dir4;
+    use crate::grid::Grid;
+
+    const CITY: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    type Crucible = ((i32, i32), Option<Dir4>, u32);
+
+    // The crucible can go straight for at most `max` blocks and has to go at least `min` before it turns or stops
+    fn crucible(grid: &Grid<u32>, min: u32, max: u32, heuristic: bool) -> Option<u32> {
+        let end = (grid.width() as i32 - 1, grid.height() as i32 - 1);
+
+        let neighbours = |&(pos, dir, run): &Crucible| {
+            Dir4::ALL
+                .into_iter()
+                .filter(move |&next| match dir {
+                    None => true,
+                    Some(dir) if next == dir => run < max,
+                    Some(dir) => next != dir.opposite() && run >= min,
+                })
+                .filter_map(move |next| {
+                    let (x, y) = next.step_on(grid, pos)?;
+                    let run = if Some(next) == dir { run + 1 } else { 1 };
+                    Some((((x, y), Some(next), run), grid[(x as usize, y as usize)]))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let goal = |&(pos, _, run): &Crucible| pos == end && run >= min;
+
+        let path = if heuristic {
+            astar(((0, 0), None, 0), neighbours, |&((x, y), _, _)| (end.0 - x + end.1 - y) as u32, goal)
+        } else {
+            dijkstra(((0, 0), None, 0), neighbours, goal)
+        };
+
+        path.map(|path| path.cost)
+    }
+
+    #[test]
+    fn crucibles() -> Result<(), crate::error::AocError> {
+        let grid = Grid::parse_with(CITY, |ch| ch.to_digit(10).ok_or(()))?;
+
+        assert_eq!(crucible(&grid, 1, 3, false), Some(102));
+        assert_eq!(crucible(&grid, 1, 3, true), Some(102));
+        assert_eq!(crucible(&grid, 4, 10, false), Some(94));
+        assert_eq!(crucible(&grid, 4, 10, true), Some(94));
+        Ok(())
+    }
+
+    #[test]
+    fn reconstructs_the_path() {
+        // 0 -> 1 -> 2 -> 3 costs 3, 0 -> 3 directly costs 5
+        let edges = |&n: &u32| match n {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+
+        assert_eq!(dijkstra(0, edges, |&n| n == 3), Some(Path { cost: 3, states: vec![0, 1, 2, 3] }));
+        assert_eq!(dijkstra(0, edges, |&n| n == 4), None);
+        assert_eq!(dijkstra_all(0, edges), HashMap::from([(0, 0), (1, 1), (2, 2), (3, 3)]));
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+use std::process::ExitCode;
+use std::time::{ Duration, Instant };
+
+use crate::bench::{ bench, bench_runs };
+use crate::error::AocError;
+use crate::source::RunArgs;
+
+
+// One day's puzzle. `parse` builds whatever both parts share (a grid, a graph, the list of machines) once, and each
+// part works from that, so runners, benchmarks and tests can all drive any day the same way.
+//
+// The few puzzles with a parameter that changes between the example and the real input (10 pairs vs 1000, say)
+// keep it on the solution itself and read it in `from_args`.
+pub trait Solution: Default {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    fn from_args(_args: &RunArgs) -> Result<Self, AocError> {
+        Ok(Self::default())
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(&self, input: &Self::Input) -> Result<impl Display, AocError>;
+
+    // Days that only have a first part (so far) leave this alone
+    fn part2(&self, _input: &Self::Input) -> Result<impl Display, AocError> {
+        Err::<String, _>(AocError::NoSolution { year: Self::YEAR, day: Self::DAY, part: Some(2) })
+    }
+}
+
+// How long each phase of a run took
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+// Parses the input and solves one part of it
+pub fn solve<S: Solution>(solution: &S, input: &str, part: u8) -> Result<(String, Timings), AocError> {
+    let now = Instant::now();
+    let parsed = solution.parse(input)?;
+    let parse = now.elapsed();
+
+    let now = Instant::now();
+    let answer = solve_parsed(solution, &parsed, part)?;
+    let solve = now.elapsed();
+
+    Ok((answer, Timings { parse, solve }))
+}
+
+// Solves one part of an already-parsed input
+pub fn solve_parsed<S: Solution>(solution: &S, input: &S::Input, part: u8) -> Result<String, AocError> {
+    match part {
+        1 => Ok(solution.part1(input)?.to_string()),
+        2 => Ok(solution.part2(input)?.to_string()),
+        _ => Err(AocError::NoSolution { year: S::YEAR, day: S::DAY, part: Some(part) }),
+    }
+}
+
+// The whole of a part binary's main: reads the input from wherever the command line says, solves the part and
+// prints the answer with its timings. With --bench [runs] it solves it repeatedly instead and prints the timing
+// stats as a line of JSON for `aoc bench` to pick up. Anything that goes wrong is printed as a readable message
+// rather than the Debug dump returning an error from main would give.
+pub fn run_solution<S: Solution>(part: u8) -> ExitCode {
+    match run::<S>(part) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+fn run<S: Solution>(part: u8) -> Result<(), AocError> {
+    let args = RunArgs::from_env()?;
+    let solution = S::from_args(&args)?;
+    let input = args.source.read_string(S::YEAR, S::DAY)?;
+
+    if let Some(runs) = bench_runs(&args)? {
+        let (answer, result) = bench(&solution, &input, part, runs)?;
+        let json = serde_json::to_string(&result).map_err(|e| AocError::io("<stdout>", e.into()))?;
+
+        println!("Result: {}", answer);
+        println!("Bench: {}", json);
+        return Ok(());
+    }
+
+    let (answer, timings) = solve(&solution, &input, part)?;
+
+    println!("Result: {}", answer);
+    println!("Parse time: {:?}, solve time: {:?}", timings.parse, timings.solve);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, AocError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, line)| line.parse().map_err(|_| AocError::parse(i + 1, 1, format!("not a number: {:?}", line))))
+                .collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<impl Display, AocError> {
+            Ok(input.iter().sum::<u32>())
+        }
+    }
+
+    #[test]
+    fn solves_each_part() -> Result<(), AocError> {
+        assert_eq!(solve(&Sum, "1\n2\n3\n", 1)?.0, "6");
+        assert!(matches!(solve(&Sum, "1\n", 2), Err(AocError::NoSolution { part: Some(2), .. })));
+        assert!(matches!(solve(&Sum, "1\nx\n", 1), Err(AocError::Parse { line: 2, .. })));
+        Ok(())
+    }
+}
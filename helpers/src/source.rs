@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::io::{ self, Read };
+use std::path::{ Path, PathBuf };
+use std::str::FromStr;
+
+use crate::error::AocError;
+use crate::examples::get_example_with_config;
+use crate::input::{ get_puzzle_input_with_config, read_from_file_as_string, Config };
+
+
+// Where a solution's input comes from, picked at runtime instead of with a cargo feature
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+
+    // The real puzzle input, served from the cache when it's there and downloaded otherwise
+    #[default]
+    Live,
+
+    // The cached puzzle input only; a cache miss is an error rather than a request
+    Cached,
+
+    // A named example. Example("") is example.txt, Example("2") is example2.txt, and so on, looked up in the
+    // current directory and then src/bin. A numbered example with no file falls back to the matching block
+    // extracted from the puzzle description.
+    Example(String),
+
+    // An arbitrary file
+    File(PathBuf),
+
+    // Whatever gets piped in
+    Stdin,
+}
+
+impl InputSource {
+    pub fn is_example(&self) -> bool {
+        matches!(self, InputSource::Example(_))
+    }
+
+    pub fn read_string(&self, year: u16, day: u8) -> Result<String, AocError> {
+        self.read_string_with_config(year, day, &Config::from_env())
+    }
+
+    pub fn read_lines(&self, year: u16, day: u8) -> Result<Vec<String>, AocError> {
+        Ok(self.read_string(year, day)?.lines().map(String::from).collect())
+    }
+
+    pub fn read_string_with_config(&self, year: u16, day: u8, config: &Config) -> Result<String, AocError> {
+        match self {
+            InputSource::Live => get_puzzle_input_with_config(year, day, config),
+
+            InputSource::Cached => {
+                let offline = Config { offline: true, ..config.clone() };
+                get_puzzle_input_with_config(year, day, &offline)
+            },
+
+            InputSource::Example(name) => read_example(name, year, day, config),
+
+            InputSource::File(path) => read_from_file_as_string(path),
+
+            InputSource::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf).map_err(|e| AocError::io("<stdin>", e))?;
+                Ok(buf)
+            },
+        }
+    }
+}
+
+fn read_example(name: &str, year: u16, day: u8, config: &Config) -> Result<String, AocError> {
+    let filename = format!("example{}.txt", name);
+
+    for dir in [Path::new("."), Path::new("src/bin")] {
+        let path = dir.join(&filename);
+        if path.is_file() {
+            return read_from_file_as_string(path);
+        }
+    }
+
+    // No file on disk, so try the examples pulled from the puzzle page
+    let number = if name.is_empty() { Some(1) } else { name.parse::<usize>().ok() };
+
+    if let Some(number) = number {
+        if let Some(example) = get_example_with_config(year, day, number, config)? {
+            return Ok(example.input);
+        }
+    }
+
+    Err(AocError::io(filename, io::Error::new(io::ErrorKind::NotFound, "no example file or extracted example by that name")))
+}
+
+// Parses "live", "cached", "example", "example:<name>", "-" or "stdin", "file:<path>", or a bare path. Only
+// "example" on its own or with a colon names an example, so a path like example.txt or examples/big.txt is a file.
+impl FromStr for InputSource {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Ok(match s {
+            "" => return Err(AocError::InvalidArgument("empty input source".to_string())),
+            "live" => InputSource::Live,
+            "cached" => InputSource::Cached,
+            "example" => InputSource::Example(String::new()),
+            "-" | "stdin" => InputSource::Stdin,
+            _ => {
+                if let Some(path) = s.strip_prefix("file:") {
+                    InputSource::File(PathBuf::from(path))
+                } else if let Some(name) = s.strip_prefix("example:") {
+                    InputSource::Example(name.to_string())
+                } else {
+                    InputSource::File(PathBuf::from(s))
+                }
+            },
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Live => write!(f, "live"),
+            InputSource::Cached => write!(f, "cached"),
+            InputSource::Example(name) if name.is_empty() => write!(f, "example"),
+            InputSource::Example(name) => write!(f, "example:{}", name),
+            InputSource::File(path) => write!(f, "file:{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+// Command-line options shared by every solution binary:
+//
+//     --input <source>    where to read the input from (see InputSource), defaulting to $AOC_INPUT or live
+//     --example [name]    shorthand for --input example:<name>
+//     --set key=value     a puzzle parameter, e.g. --set pairs=10
+//
+// Anything else is left in `rest` for the binary to deal with.
+#[derive(Debug, Clone, Default)]
+pub struct RunArgs {
+    pub source: InputSource,
+    pub params: HashMap<String, String>,
+    pub rest: Vec<String>,
+
+    // Whether --input/--example was given, so AOC_INPUT doesn't override it
+    explicit_source: bool,
+}
+
+impl RunArgs {
+    pub fn from_env() -> Result<Self, AocError> {
+        let mut args = Self::parse(env::args().skip(1))?;
+
+        if !args.explicit_source {
+            if let Ok(source) = env::var("AOC_INPUT") {
+                args.source = source.parse()?;
+            }
+        }
+
+        Ok(args)
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, AocError> {
+        let mut parsed = Self::default();
+        let mut source: Option<InputSource> = None;
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" | "-i" => {
+                    let value = args.next().ok_or_else(|| AocError::InvalidArgument("--input needs a value".to_string()))?;
+                    source = Some(value.parse()?);
+                },
+
+                "--example" | "-e" => {
+                    let name = match args.peek() {
+                        Some(next) if !next.starts_with('-') => args.next().unwrap(),
+                        _ => String::new(),
+                    };
+                    source = Some(InputSource::Example(name));
+                },
+
+                "--set" => {
+                    let value = args.next().ok_or_else(|| AocError::InvalidArgument("--set needs key=value".to_string()))?;
+                    let (key, val) = value
+                        .split_once('=')
+                        .ok_or_else(|| AocError::InvalidArgument(format!("expected key=value, got {:?}", value)))?;
+
+                    parsed.params.insert(key.to_string(), val.to_string());
+                },
+
+                _ => {
+                    if let Some(value) = arg.strip_prefix("--input=") {
+                        source = Some(value.parse()?);
+                    } else {
+                        parsed.rest.push(arg);
+                    }
+                },
+            }
+        }
+
+        if let Some(source) = source {
+            parsed.source = source;
+            parsed.explicit_source = true;
+        }
+
+        Ok(parsed)
+    }
+
+    // A puzzle parameter, falling back to `example` or `real` depending on whether we're running an example. This is
+    // for the puzzles that change a constant between the example and the real input (10 pairs vs 1000, say).
+    pub fn param<T: FromStr>(&self, key: &str, example: T, real: T) -> Result<T, AocError> {
+        match self.params.get(key) {
+            Some(value) => value
+                .parse::<T>()
+                .map_err(|_| AocError::InvalidArgument(format!("bad value for {}: {:?}", key, value))),
+            None if self.source.is_example() => Ok(example),
+            None => Ok(real),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_sources() -> Result<(), AocError> {
+        assert_eq!("live".parse::<InputSource>()?, InputSource::Live);
+        assert_eq!("cached".parse::<InputSource>()?, InputSource::Cached);
+        assert_eq!("example".parse::<InputSource>()?, InputSource::Example(String::new()));
+        assert_eq!("example:2".parse::<InputSource>()?, InputSource::Example("2".to_string()));
+        assert_eq!("-".parse::<InputSource>()?, InputSource::Stdin);
+        assert_eq!("file:in.txt".parse::<InputSource>()?, InputSource::File(PathBuf::from("in.txt")));
+        assert_eq!("inputs/day01.txt".parse::<InputSource>()?, InputSource::File(PathBuf::from("inputs/day01.txt")));
+
+        // Paths that merely start with "example" are still paths
+        assert_eq!("example.txt".parse::<InputSource>()?, InputSource::File(PathBuf::from("example.txt")));
+        assert_eq!("example2".parse::<InputSource>()?, InputSource::File(PathBuf::from("example2")));
+        assert_eq!("examples/foo.txt".parse::<InputSource>()?, InputSource::File(PathBuf::from("examples/foo.txt")));
+        Ok(())
+    }
+
+    #[test]
+    fn sources_round_trip_through_display() -> Result<(), AocError> {
+        for source in ["live", "cached", "example", "example:2", "stdin", "file:example.txt"] {
+            assert_eq!(source.parse::<InputSource>()?.to_string(), source);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parses_run_args() -> Result<(), AocError> {
+        let parsed = RunArgs::parse(args("--example 2 --set pairs=10 --part 1"))?;
+
+        assert_eq!(parsed.source, InputSource::Example("2".to_string()));
+        assert_eq!(parsed.param("pairs", 0usize, 0)?, 10);
+        assert_eq!(parsed.rest, args("--part 1"));
+
+        let bare = RunArgs::parse(args("--example --set x=1"))?;
+        assert_eq!(bare.source, InputSource::Example(String::new()));
+        Ok(())
+    }
+
+    #[test]
+    fn params_default_by_source() -> Result<(), AocError> {
+        assert_eq!(RunArgs::parse(args("--example"))?.param("pairs", 10, 1000)?, 10);
+        assert_eq!(RunArgs::parse(args("--input cached"))?.param("pairs", 10, 1000)?, 1000);
+        assert!(matches!(RunArgs::parse(args("--set pairs=abc"))?.param("pairs", 10, 1000), Err(AocError::InvalidArgument(_))));
+        assert!(matches!(RunArgs::parse(args("--set pairs")), Err(AocError::InvalidArgument(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::point::Coords;
+
+
+// A k-d tree over a fixed set of points, for nearest-neighbour questions that would otherwise mean measuring from a
+// point to every other one. The points are referred to by their index in the slice the tree was built from.
+#[derive(Debug, Clone)]
+pub struct KdTree<const D: usize> {
+    points: Vec<[i64; D]>,
+
+    // The tree laid out like a binary heap turned on its side: each range of `order` is a subtree, with its median
+    // (the splitting point) in the middle and the two halves either side of it
+    order: Vec<usize>,
+}
+
+impl<const D: usize> KdTree<D> {
+    pub fn new<P: Coords<D>>(points: &[P]) -> Self {
+        let points: Vec<[i64; D]> = points.iter().map(|p| p.coords()).collect();
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(&points, &mut order, 0);
+
+        KdTree { points, order }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    // The k points closest to `target` as (squared distance, index) pairs, nearest first. Ties go to the lower index,
+    // so asking for more neighbours only ever adds to the end of the list.
+    pub fn nearest<P: Coords<D>>(&self, target: &P, k: usize) -> Vec<(i64, usize)> {
+        let mut best: BinaryHeap<(i64, usize)> = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search(&target.coords(), k, 0, self.order.len(), 0, &mut best);
+        }
+        best.into_sorted_vec()
+    }
+
+    fn search(&self, target: &[i64; D], k: usize, lo: usize, hi: usize, axis: usize, best: &mut BinaryHeap<(i64, usize)>) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        let dist: i64 = (0..D).map(|i| (point[i] - target[i]) * (point[i] - target[i])).sum();
+        if best.len() < k || (dist, index) < *best.peek().unwrap() {
+            best.push((dist, index));
+            if best.len() > k {
+                best.pop();
+            }
+        }
+
+        // Search the side of the split the target is on first, and only cross over if the splitting plane is close
+        // enough that something over there could still make the list
+        let gap = target[axis] - point[axis];
+        let (near, far) = if gap < 0 { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        let next = (axis + 1) % D;
+
+        self.search(target, k, near.0, near.1, next, best);
+        if best.len() < k || gap * gap <= best.peek().unwrap().0 {
+            self.search(target, k, far.0, far.1, next, best);
+        }
+    }
+}
+
+fn build<const D: usize>(points: &[[i64; D]], order: &mut [usize], axis: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by_key(mid, |&i| points[i][axis]);
+
+    let (left, right) = order.split_at_mut(mid);
+    build(points, left, (axis + 1) % D);
+    build(points, &mut right[1..], (axis + 1) % D);
+}
+
+// Every pair of points, closest first, as (i, j, squared distance) with i < j. Pairs at the same distance come out
+// ordered by i and then j. Nothing is worked out until it's asked for, so taking the first thousand pairs out of a
+// thousand points only looks at a handful of neighbours per point rather than all half a million pairs.
+pub fn closest_pairs<P: Coords<D>, const D: usize>(points: &[P]) -> ClosestPairs<D> {
+    let tree = KdTree::new(points);
+    let mut pairs = ClosestPairs {
+        neighbours: vec![Neighbours::default(); tree.len()],
+        heap: BinaryHeap::with_capacity(tree.len()),
+        tree,
+    };
+
+    for i in 0..pairs.tree.len() {
+        pairs.queue_next(i);
+    }
+
+    pairs
+}
+
+#[derive(Debug, Clone)]
+pub struct ClosestPairs<const D: usize> {
+    tree: KdTree<D>,
+    neighbours: Vec<Neighbours>,
+
+    // The closest pair each point hasn't handed out yet. Each point's neighbours come in order, so merging them
+    // through the heap puts everything in order.
+    heap: BinaryHeap<Reverse<(i64, usize, usize)>>,
+}
+
+// The neighbours of one point with a higher index, nearest first, fetched from the tree a batch at a time
+#[derive(Debug, Clone, Default)]
+struct Neighbours {
+    fetched: usize,
+    found: Vec<(i64, usize)>,
+    next: usize,
+}
+
+impl<const D: usize> ClosestPairs<D> {
+    fn queue_next(&mut self, i: usize) {
+        let total = self.tree.len();
+        let list = &mut self.neighbours[i];
+
+        // Out of neighbours: fetch twice as many and pick up where we left off. The tree breaks ties by index, so
+        // the new list starts with the old one. A batch can turn up nothing new with a higher index, hence the loop.
+        while list.next == list.found.len() && list.fetched < total {
+            list.fetched = (list.fetched * 2).max(8).min(total);
+
+            let point = self.tree.points[i];
+            list.found = self
+                .tree
+                .nearest(&Array(point), list.fetched)
+                .into_iter()
+                .filter(|&(_, j)| j > i)
+                .collect();
+        }
+
+        if let Some(&(dist, j)) = list.found.get(list.next) {
+            list.next += 1;
+            self.heap.push(Reverse((dist, i, j)));
+        }
+    }
+}
+
+impl<const D: usize> Iterator for ClosestPairs<D> {
+    type Item = (usize, usize, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((dist, i, j)) = self.heap.pop()?;
+        self.queue_next(i);
+        Some((i, j, dist))
+    }
+}
+
+// Lets the tree be asked about one of its own points
+#[derive(Clone, Copy)]
+struct Array<const D: usize>([i64; D]);
+
+impl<const D: usize> Coords<D> for Array<D> {
+    fn coords(&self) -> [i64; D] {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::{ Point2, Point3 };
+
+    fn scattered(n: usize) -> Vec<Point3> {
+        // A small linear congruential generator, so the test doesn't need a rand dependency
+        let mut seed: i64 = 12345;
+        let mut next = move || {
+            seed = (seed * 1103515245 + 12345) % 2147483648;
+            seed % 1000
+        };
+
+        (0..n).map(|_| Point3::new(next(), next(), next())).collect()
+    }
+
+    #[test]
+    fn nearest_matches_brute_force() {
+        let points = scattered(300);
+        let tree = KdTree::new(&points);
+
+        for target in [points[0], points[17], Point3::new(500, 500, 500), Point3::new(-50, 2000, 3)] {
+            let mut expected: Vec<(i64, usize)> = points.iter().map(|p| p.dist_sq(&target)).zip(0..).collect();
+            expected.sort();
+            expected.truncate(10);
+
+            assert_eq!(tree.nearest(&target, 10), expected);
+        }
+    }
+
+    #[test]
+    fn pairs_come_out_in_order() {
+        let points = scattered(200);
+
+        let mut expected: Vec<(i64, usize, usize)> = Vec::new();
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                expected.push((points[i].dist_sq(&points[j]), i, j));
+            }
+        }
+        expected.sort();
+
+        let pairs: Vec<(i64, usize, usize)> = closest_pairs(&points).map(|(i, j, dist)| (dist, i, j)).collect();
+        assert_eq!(pairs, expected);
+    }
+
+    #[test]
+    fn handles_ties_and_tiny_inputs() {
+        let square = [Point2::new(0, 0), Point2::new(1, 0), Point2::new(0, 1), Point2::new(1, 1)];
+        let pairs: Vec<_> = closest_pairs(&square).collect();
+        assert_eq!(pairs, vec![(0, 1, 1), (0, 2, 1), (1, 3, 1), (2, 3, 1), (0, 3, 2), (1, 2, 2)]);
+
+        assert_eq!(closest_pairs(&[Point2::ORIGIN]).count(), 0);
+        assert_eq!(closest_pairs::<Point2, 2>(&[]).count(), 0);
+    }
+}
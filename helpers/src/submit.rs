@@ -0,0 +1,296 @@
+use std::fmt;
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::time::Duration;
+
+use crate::error::AocError;
+use crate::input::{ write_file, Config };
+
+
+// What AoC had to say about a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswerResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited { wait: Duration },
+    AlreadySolved,
+}
+
+impl AnswerResult {
+
+    // Only verdicts about the answer itself are worth remembering. Rate limits and "already solved" say nothing about
+    // whether the guess was right.
+    fn ledger_tag(&self) -> Option<&'static str> {
+        match self {
+            AnswerResult::Correct => Some("correct"),
+            AnswerResult::TooHigh => Some("too_high"),
+            AnswerResult::TooLow => Some("too_low"),
+            AnswerResult::Incorrect => Some("incorrect"),
+            AnswerResult::RateLimited { .. } | AnswerResult::AlreadySolved => None,
+        }
+    }
+
+    fn from_ledger_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "correct" => Some(AnswerResult::Correct),
+            "too_high" => Some(AnswerResult::TooHigh),
+            "too_low" => Some(AnswerResult::TooLow),
+            "incorrect" => Some(AnswerResult::Incorrect),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for AnswerResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerResult::Correct => write!(f, "correct"),
+            AnswerResult::TooHigh => write!(f, "too high"),
+            AnswerResult::TooLow => write!(f, "too low"),
+            AnswerResult::Incorrect => write!(f, "incorrect"),
+            AnswerResult::RateLimited { wait } => write!(f, "rate limited, wait {:?}", wait),
+            AnswerResult::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+// Submits an answer for the given puzzle part, checking the local ledger of past guesses first. A guess that's
+// already been made returns the recorded verdict without being resent, and a guess that falls outside a known
+// too-high or too-low bound is refused outright.
+pub fn submit_answer(year: u16, day: u8, part: u8, answer: impl fmt::Display) -> Result<AnswerResult, AocError> {
+    submit_answer_with_config(year, day, part, answer, &Config::from_env())
+}
+
+// Same as submit_answer, but with explicit settings instead of reading them from the environment
+pub fn submit_answer_with_config(year: u16, day: u8, part: u8, answer: impl fmt::Display, config: &Config) -> Result<AnswerResult, AocError> {
+    let answer = answer.to_string();
+    let answer = answer.trim();
+
+    if answer.is_empty() {
+        return Err(AocError::GuessRefused("the answer is empty".to_string()));
+    }
+
+    let ledger_path = config.ledger_path(year, day);
+    let mut ledger = Ledger::load(&ledger_path)?;
+
+    if let Some(result) = ledger.check(part, answer)? {
+        return Ok(result);
+    }
+
+    if config.offline {
+        return Err(AocError::Offline { year, day, path: ledger_path });
+    }
+
+    let session_token = config.token.as_deref().ok_or(AocError::MissingToken)?;
+    let url = config.answer_url(year, day);
+
+    let response = config.client
+        .post(&url)
+        .header("Cookie", format!("session={}", session_token))
+        .form(&[("level", part.to_string().as_str()), ("answer", answer)])
+        .send()?;
+
+    if !response.status().is_success() {
+        return Err(AocError::from_status(response.status().as_u16(), &url, year, day));
+    }
+
+    let result = parse_answer_response(&response.text()?)?;
+
+    if result.ledger_tag().is_some() {
+        ledger.record(part, answer, &result);
+        ledger.save(&ledger_path)?;
+    }
+
+    Ok(result)
+}
+
+// Pulls the verdict out of the HTML AoC sends back after a submission
+pub fn parse_answer_response(html: &str) -> Result<AnswerResult, AocError> {
+    if html.contains("That's the right answer") {
+        Ok(AnswerResult::Correct)
+
+    } else if html.contains("You gave an answer too recently") {
+        Ok(AnswerResult::RateLimited { wait: parse_wait(html).unwrap_or(Duration::from_secs(60)) })
+
+    } else if html.contains("Did you already complete it") {
+        Ok(AnswerResult::AlreadySolved)
+
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too high") {
+            Ok(AnswerResult::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Ok(AnswerResult::TooLow)
+        } else {
+            Ok(AnswerResult::Incorrect)
+        }
+
+    } else {
+        Err(AocError::UnexpectedResponse("couldn't find a verdict in the answer page".to_string()))
+    }
+}
+
+// Parses the "You have 1m 23s left to wait." sentence from a rate-limit response
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    let mut secs = 0u64;
+    for part in html[start..end].split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let n = n.parse::<u64>().ok()?;
+
+        secs += match unit {
+            "h" => n * 3600,
+            "m" => n * 60,
+            "s" => n,
+            _ => return None,
+        };
+    }
+
+    Some(Duration::from_secs(secs))
+}
+
+// Every guess made so far for one day, stored as tab-separated "part, answer, verdict" lines next to the cached input
+#[derive(Debug, Default)]
+pub struct Ledger {
+    entries: Vec<(u8, String, AnswerResult)>,
+}
+
+impl Ledger {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let entries = fs::read_to_string(path)
+            .map_err(|e| AocError::io(path, e))?
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                let [part, answer, tag] = fields[..] else {
+                    return Err(AocError::parse(i + 1, 1, format!("expected 3 tab-separated fields in ledger, got {}", fields.len())));
+                };
+
+                let part = part.parse::<u8>().map_err(|e| AocError::parse(i + 1, 1, format!("bad part number: {}", e)))?;
+                let result = AnswerResult::from_ledger_tag(tag)
+                    .ok_or_else(|| AocError::parse(i + 1, line.len() - tag.len() + 1, format!("unknown verdict {:?}", tag)))?;
+
+                Ok((part, answer.to_string(), result))
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let contents: String = self.entries
+            .iter()
+            .filter_map(|(part, answer, result)| Some(format!("{}\t{}\t{}\n", part, answer, result.ledger_tag()?)))
+            .collect();
+
+        write_file(path, &contents)
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, result: &AnswerResult) {
+        self.entries.push((part, answer.to_string(), result.clone()));
+    }
+
+    // Decides whether a guess needs to go to the server at all. Returns the known verdict for a repeated guess or
+    // AlreadySolved if the part has a correct answer on record, errors for a guess that's ruled out by a known
+    // bound, and None if the guess is new and plausible.
+    pub fn check(&self, part: u8, answer: &str) -> Result<Option<AnswerResult>, AocError> {
+        let guesses: Vec<&(u8, String, AnswerResult)> = self.entries.iter().filter(|(p, _, _)| *p == part).collect();
+
+        if let Some((_, _, result)) = guesses.iter().find(|(_, a, _)| a == answer) {
+            return Ok(Some(result.clone()));
+        }
+
+        if guesses.iter().any(|(_, _, result)| *result == AnswerResult::Correct) {
+            return Ok(Some(AnswerResult::AlreadySolved));
+        }
+
+        // Bounds only make sense for numeric answers
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(None);
+        };
+
+        for (_, guess, result) in guesses {
+            let Ok(bound) = guess.parse::<i128>() else { continue };
+
+            match result {
+                AnswerResult::TooHigh if value >= bound => {
+                    return Err(AocError::GuessRefused(format!("{} can't be right: {} was already too high", value, bound)));
+                },
+                AnswerResult::TooLow if value <= bound => {
+                    return Err(AocError::GuessRefused(format!("{} can't be right: {} was already too low", value, bound)));
+                },
+                _ => {},
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl Config {
+
+    // URL answers for a given day are posted to
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url.trim_end_matches('/'), year, day)
+    }
+
+    // Path of the ledger of past guesses for a given day, e.g. <cache_dir>/2025/day05_answers.tsv
+    pub fn ledger_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir.join(year.to_string()).join(format!("day{:02}_answers.tsv", day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_each_verdict() -> Result<(), AocError> {
+        let wrap = |s: &str| format!("<main><article><p>{}</p></article></main>", s);
+
+        assert_eq!(parse_answer_response(&wrap("That's the right answer! You are one gold star closer."))?, AnswerResult::Correct);
+        assert_eq!(parse_answer_response(&wrap("That's not the right answer; your answer is too high."))?, AnswerResult::TooHigh);
+        assert_eq!(parse_answer_response(&wrap("That's not the right answer; your answer is too low."))?, AnswerResult::TooLow);
+        assert_eq!(parse_answer_response(&wrap("That's not the right answer. If you're stuck..."))?, AnswerResult::Incorrect);
+        assert_eq!(
+            parse_answer_response(&wrap("You gave an answer too recently. You have 1m 23s left to wait."))?,
+            AnswerResult::RateLimited { wait: Duration::from_secs(83) }
+        );
+        assert_eq!(
+            parse_answer_response(&wrap("You don't seem to be solving the right level.  Did you already complete it?"))?,
+            AnswerResult::AlreadySolved
+        );
+        assert!(parse_answer_response("<html></html>").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn ledger_blocks_repeats_and_out_of_bounds_guesses() -> Result<(), AocError> {
+        let mut ledger = Ledger::default();
+        ledger.record(1, "500", &AnswerResult::TooHigh);
+        ledger.record(1, "100", &AnswerResult::TooLow);
+
+        assert_eq!(ledger.check(1, "500")?, Some(AnswerResult::TooHigh));
+        assert!(matches!(ledger.check(1, "600"), Err(AocError::GuessRefused(_))));
+        assert!(ledger.check(1, "50").is_err());
+        assert_eq!(ledger.check(1, "300")?, None);
+
+        // Bounds are per part
+        assert_eq!(ledger.check(2, "600")?, None);
+
+        ledger.record(1, "300", &AnswerResult::Correct);
+        assert_eq!(ledger.check(1, "301")?, Some(AnswerResult::AlreadySolved));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fs;
+
+use aoc_helpers::answers::{ check_all, check_example, check_part, format_check_report, Answers, Outcome };
+use aoc_helpers::registry::Registry;
+use aoc_helpers::Config;
+
+// Where the accepted answers are kept, relative to the repo root
+const ANSWERS: &str = "answers.toml";
+
+
+// Every registered day against its recorded answers, using whatever inputs are in the cache or checked in, plus the
+// examples. Days without an input or a recorded answer show up as skipped in the report (run with --nocapture to see it).
+#[test]
+fn recorded_answers_still_come_out() -> Result<(), Box<dyn Error>> {
+    let registry = Registry::discover(&Registry::default_root())?;
+    let answers = Answers::load(&registry.root.join(ANSWERS))?;
+
+    let rows = check_all(&registry, &answers, &Config::from_env());
+    let report = format_check_report(&rows);
+    println!("{}", report);
+
+    assert!(!rows.iter().any(|row| row.outcome.is_failure()), "some answers changed:\n{}", report);
+    Ok(())
+}
+
+#[test]
+fn wrong_answers_are_caught() -> Result<(), Box<dyn Error>> {
+    let registry = Registry::discover(&Registry::default_root())?;
+    let entry = registry.find(2022, 1).expect("2022 day 1 should be registered");
+
+    // 2022 day 1's example stands in for the real input
+    let cache_dir = std::env::temp_dir().join(format!("aoc_answers_{}", std::process::id()));
+    let config = Config { cache_dir, ..Config::from_env() };
+    let path = config.cache_path(2022, 1);
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::copy(entry.dir.join("example1_1.txt"), &path)?;
+
+    let answers = Answers::from_toml("[2022.day01]\npart1 = 24000\npart2 = 1\n\n[2022.day01.example1_1]\npart1 = 24001\n")?;
+
+    assert!(matches!(check_part(entry, 1, &answers, &config), Outcome::Passed));
+    assert!(matches!(
+        check_part(entry, 2, &answers, &config),
+        Outcome::Failed { expected, actual } if expected == "1" && actual == "45000"
+    ));
+    assert!(matches!(
+        check_example(entry, 1, "1_1", &answers),
+        Outcome::Failed { expected, actual } if expected == "24001" && actual == "24000"
+    ));
+
+    fs::remove_dir_all(&config.cache_dir)?;
+    Ok(())
+}
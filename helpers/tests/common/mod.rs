@@ -0,0 +1,144 @@
+// A small stand-in for adventofcode.com. It serves canned responses keyed by method and path on a local port and
+// records every request it sees, so the helpers can be exercised without a network connection or a real session.
+
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io::{ prelude::*, BufReader };
+use std::net::{ TcpListener, TcpStream };
+use std::path::PathBuf;
+use std::sync::{ Arc, Mutex };
+use std::thread;
+
+use aoc_helpers::Config;
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+}
+
+type Routes = Arc<Mutex<HashMap<(String, String), (u16, String)>>>;
+
+pub struct StandInServer {
+    pub base_url: String,
+    routes: Routes,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl StandInServer {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let routes: Routes = Arc::new(Mutex::new(HashMap::new()));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let (r, q) = (Arc::clone(&routes), Arc::clone(&requests));
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream, &r, &q);
+            }
+        });
+
+        Self { base_url, routes, requests }
+    }
+
+    // Serves the given status and body for GET requests to path
+    pub fn get(&self, path: &str, status: u16, body: &str) -> &Self {
+        self.route("GET", path, status, body)
+    }
+
+    // Serves the given status and body for POST requests to path
+    pub fn post(&self, path: &str, status: u16, body: &str) -> &Self {
+        self.route("POST", path, status, body)
+    }
+
+    pub fn route(&self, method: &str, path: &str, status: u16, body: &str) -> &Self {
+        self.routes.lock().unwrap().insert((method.to_string(), path.to_string()), (status, body.to_string()));
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    // A config pointed at this server with its own empty cache directory
+    pub fn config(&self, name: &str) -> Config {
+        let cache_dir = temp_dir(name);
+
+        Config {
+            cache_dir,
+            offline: false,
+            base_url: self.base_url.clone(),
+            token: Some("test-session".to_string()),
+            ..Config::from_env()
+        }
+    }
+}
+
+// A fresh, empty directory under the system temp dir that's unique to this test process
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc_helpers_it_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+fn handle(stream: TcpStream, routes: &Routes, requests: &Arc<Mutex<Vec<Request>>>) {
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() || line.trim().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_string());
+        }
+    }
+
+    let length = headers.get("content-length").and_then(|l| l.parse::<usize>().ok()).unwrap_or(0);
+    let mut body = vec![0u8; length];
+    let _ = reader.read_exact(&mut body);
+
+    let (status, response_body) = routes
+        .lock()
+        .unwrap()
+        .get(&(method.clone(), path.clone()))
+        .cloned()
+        .unwrap_or((404, "Not Found".to_string()));
+
+    requests.lock().unwrap().push(Request { method, path, headers, body: String::from_utf8_lossy(&body).into_owned() });
+
+    let response = format!(
+        "HTTP/1.1 {} Stand-in\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        response_body.len(),
+        response_body
+    );
+
+    let mut stream = stream;
+    let _ = stream.write_all(response.as_bytes());
+    let _ = stream.flush();
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use aoc_helpers::*;
+use common::StandInServer;
+use std::error::Error;
+use std::fs;
+use std::time::{ Duration, SystemTime };
+
+const PART1_PAGE: &str = include_str!("fixtures/day_part1.html");
+const BOTH_PAGE: &str = include_str!("fixtures/day_both.html");
+
+#[test]
+fn extracts_numbered_examples_with_answers() {
+    let examples = extract_examples(PART1_PAGE);
+
+    assert_eq!(examples.len(), 2);
+
+    assert_eq!(examples[0].number, 1);
+    assert_eq!(examples[0].part, 1);
+    assert_eq!(examples[0].input, "12\n7\n\n3\n20\n");
+    assert_eq!(examples[0].lines(), vec!["12", "7", "", "3", "20"]);
+    assert_eq!(examples[0].answer.as_deref(), Some("23"));
+
+    assert_eq!(examples[1].number, 2);
+    assert_eq!(examples[1].input, "a < b && c\n");
+    assert_eq!(examples[1].answer, None);
+}
+
+#[test]
+fn part_two_examples_are_numbered_after_part_one() {
+    let examples = extract_examples(BOTH_PAGE);
+
+    assert_eq!(examples.len(), 3);
+    assert_eq!(examples[2].number, 3);
+    assert_eq!(examples[2].part, 2);
+    assert_eq!(examples[2].input, "12,7\n3,20\n5\n");
+    assert_eq!(examples[2].answer.as_deref(), Some("47"));
+}
+
+#[test]
+fn fetched_page_is_cached() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.get("/2022/day/3", 200, BOTH_PAGE);
+
+    let config = server.config("examples_cache");
+
+    assert_eq!(get_examples_with_config(2022, 3, &config)?.len(), 3);
+    assert_eq!(get_examples_with_config(2022, 3, &config)?.len(), 3);
+
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(server.requests()[0].header("cookie"), Some("session=test-session"));
+    Ok(())
+}
+
+#[test]
+fn cached_page_is_reused_until_part_two_is_asked_for() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.get("/2022/day/3", 200, PART1_PAGE);
+
+    let config = server.config("examples_refresh");
+
+    assert_eq!(get_examples_with_config(2022, 3, &config)?.len(), 2);
+    assert_eq!(get_examples_with_config(2022, 3, &config)?.len(), 2);
+    assert_eq!(server.requests().len(), 1);
+
+    // Part 2 unlocks, but a page fetched a moment ago isn't worth asking for again yet
+    server.get("/2022/day/3", 200, BOTH_PAGE);
+    assert_eq!(get_example_with_config(2022, 3, 3, &config)?, None);
+    assert_eq!(server.requests().len(), 1);
+
+    // Once the cached page is old enough, asking for a part 2 example refreshes it, and only the once
+    let path = config.page_cache_path(2022, 3);
+    fs::File::options().write(true).open(&path)?.set_modified(SystemTime::now() - Duration::from_secs(3600))?;
+
+    assert_eq!(get_example_with_config(2022, 3, 3, &config)?.map(|e| e.part), Some(2));
+    assert_eq!(get_example_with_config(2022, 3, 3, &config)?.map(|e| e.part), Some(2));
+    assert_eq!(server.requests().len(), 2);
+
+    // Offline, whatever's cached is good enough
+    let offline = Config { offline: true, ..config };
+    assert_eq!(get_examples_with_config(2022, 3, &offline)?.len(), 3);
+    assert_eq!(server.requests().len(), 2);
+    Ok(())
+}
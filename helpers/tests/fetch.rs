@@ -0,0 +1,99 @@
+mod common;
+
+use aoc_helpers::*;
+use common::StandInServer;
+use std::error::Error;
+
+#[test]
+fn fetch_sends_session_cookie() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.get("/2025/day/1/input", 200, "L68\nR48\n");
+
+    let config = server.config("cookie");
+    let input = get_puzzle_input_with_config(2025, 1, &config)?;
+
+    assert_eq!(input, "L68\nR48\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    Ok(())
+}
+
+#[test]
+fn fetch_caches_the_response() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.get("/2024/day/3/input", 200, "mul(2,4)\n");
+
+    let config = server.config("caches");
+    get_puzzle_input_with_config(2024, 3, &config)?;
+    let second = get_puzzle_input_with_config(2024, 3, &config)?;
+
+    assert_eq!(second, "mul(2,4)\n");
+    assert_eq!(server.requests().len(), 1);
+    assert!(config.cache_path(2024, 3).is_file());
+    Ok(())
+}
+
+#[test]
+fn locked_day_is_not_unlocked_error() {
+    let server = StandInServer::start();
+    server.get("/2025/day/12/input", 404, "Please don't repeatedly request this endpoint before it unlocks!");
+
+    let config = server.config("not_found");
+    let err = get_puzzle_input_with_config(2025, 12, &config).unwrap_err();
+
+    assert!(matches!(err, AocError::NotUnlocked { year: 2025, day: 12 }));
+    assert!(!config.cache_path(2025, 12).exists());
+}
+
+#[test]
+fn rejected_session_is_bad_session_error() {
+    let server = StandInServer::start();
+    server.get("/2025/day/1/input", 400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.");
+
+    let config = server.config("bad_session");
+    let err = get_puzzle_input_with_config(2025, 1, &config).unwrap_err();
+
+    assert!(matches!(err, AocError::BadSession));
+}
+
+#[test]
+fn other_statuses_keep_the_url() {
+    let server = StandInServer::start();
+    server.get("/2025/day/1/input", 500, "oops");
+
+    let config = server.config("server_error");
+    let err = get_puzzle_input_with_config(2025, 1, &config).unwrap_err();
+
+    match err {
+        AocError::Http { status, url } => {
+            assert_eq!(status, 500);
+            assert!(url.ends_with("/2025/day/1/input"));
+        },
+        other => panic!("Expected an HTTP error, got {:?}", other),
+    }
+}
+
+#[test]
+fn missing_token_fails_before_any_request() {
+    let server = StandInServer::start();
+    server.get("/2025/day/1/input", 200, "L68\n");
+
+    let config = Config { token: None, ..server.config("no_token") };
+
+    assert!(matches!(get_puzzle_input_with_config(2025, 1, &config), Err(AocError::MissingToken)));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn lines_are_split_without_trailing_empty_line() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.get("/2022/day/1/input", 200, "1000\n2000\n\n4000\n");
+
+    let config = server.config("lines");
+    let lines = get_puzzle_input_as_lines_with_config(2022, 1, &config)?;
+
+    assert_eq!(lines, vec!["1000", "2000", "", "4000"]);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use aoc_helpers::*;
+use common::StandInServer;
+use std::error::Error;
+use std::time::Duration;
+
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article>";
+const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star closer to saving your vacation.</p></article>";
+
+#[test]
+fn submission_posts_level_and_answer() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.post("/2025/day/5/answer", 200, CORRECT);
+
+    let config = server.config("submit_post");
+    let result = submit_answer_with_config(2025, 5, 2, 344813017450467u64, &config)?;
+
+    assert_eq!(result, AnswerResult::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].body, "level=2&answer=344813017450467");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    Ok(())
+}
+
+#[test]
+fn wrong_guesses_are_never_resent() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.post("/2024/day/7/answer", 200, TOO_HIGH);
+
+    let config = server.config("submit_ledger");
+
+    assert_eq!(submit_answer_with_config(2024, 7, 1, 1000, &config)?, AnswerResult::TooHigh);
+    assert_eq!(submit_answer_with_config(2024, 7, 1, 1000, &config)?, AnswerResult::TooHigh);
+    assert!(matches!(submit_answer_with_config(2024, 7, 1, 2000, &config), Err(AocError::GuessRefused(_))));
+
+    assert_eq!(server.requests().len(), 1);
+    assert!(config.ledger_path(2024, 7).is_file());
+    Ok(())
+}
+
+#[test]
+fn rate_limits_are_not_recorded() -> Result<(), Box<dyn Error>> {
+    let server = StandInServer::start();
+    server.post("/2022/day/2/answer", 200, "<article><p>You gave an answer too recently. You have 45s left to wait.</p></article>");
+
+    let config = server.config("submit_rate_limit");
+
+    let result = submit_answer_with_config(2022, 2, 1, 15, &config)?;
+    assert_eq!(result, AnswerResult::RateLimited { wait: Duration::from_secs(45) });
+
+    submit_answer_with_config(2022, 2, 1, 15, &config)?;
+    assert_eq!(server.requests().len(), 2);
+    Ok(())
+}